@@ -0,0 +1,66 @@
+use helpers::{lazy_static, matcher::Matcher, phase, Day};
+
+const WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+lazy_static! {
+    /// Digits, written either with a figure or a word
+    static ref MATCHER: Matcher<u32> = (0..10)
+        .map(|digit| (digit.to_string(), digit))
+        .chain((0..10).map(|digit| (WORDS[digit as usize].to_owned(), digit)))
+        .collect();
+}
+
+/// <https://adventofcode.com/2023/day/1>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 1,
+    title: "Trebuchet?!",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &[],
+    part_1: None,
+    part_2: Some(|input| handle_part_2(input).to_string()),
+};
+
+fn handle_part_2(input: &str) -> u32 {
+    let lines: Vec<&str> = phase::parse(|| input.lines().collect());
+
+    phase::solve(|| lines.into_iter().map(get_calibration_from_line).sum())
+}
+
+fn get_calibration_from_line(line: &str) -> u32 {
+    // digits may overlap, like in "twone", so the last one is the match starting last
+    let first_digit = MATCHER.leftmost(line).unwrap();
+    let last_digit = MATCHER.rightmost(line).unwrap();
+
+    // given the input, each line contains at least one digit
+    first_digit.value * 10 + last_digit.value
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::get_calibration_from_line;
+
+    #[test]
+    fn test_it_works() {
+        assert_eq!(get_calibration_from_line("1abc2"), 12);
+        assert_eq!(get_calibration_from_line("111112"), 12);
+        assert_eq!(get_calibration_from_line("pqr3stu8vwx"), 38);
+        assert_eq!(get_calibration_from_line("a1b2c3d4e5f"), 15);
+        assert_eq!(get_calibration_from_line("treb7uchet"), 77);
+        assert_eq!(get_calibration_from_line("two1nine"), 29);
+        assert_eq!(get_calibration_from_line("eightwothree"), 83);
+        assert_eq!(get_calibration_from_line("abcone2threexyz"), 13);
+        assert_eq!(get_calibration_from_line("xtwone3four"), 24);
+        assert_eq!(get_calibration_from_line("4nineeightseven2"), 42);
+        assert_eq!(get_calibration_from_line("zoneight234"), 14);
+        assert_eq!(get_calibration_from_line("7pqrstsixteen"), 76);
+        assert_eq!(get_calibration_from_line("twone"), 21);
+        assert_eq!(get_calibration_from_line("nine"), 99);
+        assert_eq!(
+            get_calibration_from_line("hcfxflqvkvdfmthkjdpfzzlzzh4kdmmhvspzddfivethree"),
+            43
+        );
+    }
+}
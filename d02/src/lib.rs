@@ -0,0 +1,61 @@
+use std::cmp::max;
+
+use helpers::{lazy_static, phase, Day, Regex};
+
+lazy_static! {
+    static ref GAME_ID_REGEX: Regex = Regex::new("Game (?<id>\\d+): ").unwrap();
+    static ref GAME_DRAW_REGEX: Regex =
+        Regex::new("(?<count>\\d+) (?<color>red|green|blue)").unwrap();
+}
+
+/// <https://adventofcode.com/2023/day/2>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 2,
+    title: "Cube Conundrum",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &[],
+    part_1: None,
+    part_2: Some(|input| handle_part_2(input).to_string()),
+};
+
+fn handle_part_2(input: &str) -> u64 {
+    let games: Vec<(u64, u64, u64)> = phase::parse(|| input.lines().map(parse_game).collect());
+
+    phase::solve(|| {
+        games
+            .iter()
+            .map(|(red, green, blue)| red * green * blue)
+            .sum()
+    })
+}
+
+/// Fewest red, green and blue cubes the game could have been played with
+fn parse_game(line: &str) -> (u64, u64, u64) {
+    let (mut min_red, mut min_green, mut min_blue) = (0, 0, 0);
+
+    // remove the game id beginning
+    let cleaned_line = GAME_ID_REGEX.replace_all(line, "");
+
+    for subset in cleaned_line.split("; ") {
+        for m in GAME_DRAW_REGEX.captures_iter(subset) {
+            let count: u64 = m.name("count").unwrap().as_str().parse().unwrap();
+            let color = m.name("color").unwrap().as_str();
+
+            match color {
+                "red" => {
+                    min_red = max(min_red, count);
+                }
+                "green" => {
+                    min_green = max(min_green, count);
+                }
+                "blue" => {
+                    min_blue = max(min_blue, count);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    (min_red, min_green, min_blue)
+}
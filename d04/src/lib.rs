@@ -0,0 +1,62 @@
+use helpers::{bitset::BitSet, lazy_static, phase, Day, Regex};
+
+lazy_static! {
+    static ref CARD_NUMBER_REGEX: Regex = Regex::new("Card(\\s+)(?<id>\\d+): ").unwrap();
+    static ref NUMBER_REGEX: Regex = Regex::new("(\\d+)").unwrap();
+}
+
+/// <https://adventofcode.com/2023/day/4>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 4,
+    title: "Scratchcards",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &[],
+    part_1: None,
+    part_2: Some(|input| handle_part_2(input).to_string()),
+};
+
+fn handle_part_2(input: &str) -> usize {
+    let cards: Vec<(BitSet, BitSet)> = phase::parse(|| input.lines().map(parse_card).collect());
+
+    phase::solve(|| count_copies(&cards))
+}
+
+/// Total number of cards once each card wins copies of the next ones
+fn count_copies(cards: &[(BitSet, BitSet)]) -> usize {
+    let mut card_copies = vec![1_usize; cards.len()];
+
+    for (index, (winning_numbers, my_numbers)) in cards.iter().enumerate() {
+        let res = (winning_numbers & my_numbers).len();
+        let index_copies = card_copies[index];
+
+        for copies in &mut card_copies[index + 1..index + res + 1] {
+            *copies += index_copies;
+        }
+    }
+
+    card_copies.iter().sum()
+}
+
+/// Winning numbers and numbers of a card
+fn parse_card(line: &str) -> (BitSet, BitSet) {
+    // numbers are small, so sets of bits are enough to intersect them
+    let parse_numbers = |numbers: &str| -> BitSet {
+        NUMBER_REGEX
+            .find_iter(numbers)
+            .map(|m| m.as_str().parse().unwrap())
+            .collect()
+    };
+
+    // remove the game id beginning
+    let cleaned_line = CARD_NUMBER_REGEX.replace_all(line, "");
+
+    let mut it = cleaned_line.split('|');
+
+    let (winning_numbers_str, my_numbers_str) = (it.next().unwrap(), it.next().unwrap());
+
+    (
+        parse_numbers(winning_numbers_str),
+        parse_numbers(my_numbers_str),
+    )
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use helpers::{
+    interval::{Range, RangeMap},
+    lazy_static, phase, Day, Regex,
+};
+
+lazy_static! {
+    static ref INPUT_MAP_REGEX: Regex =
+        Regex::new("(?<map_name>.+) map:\n(?<map_values>(.+\n)*)").unwrap();
+    static ref SEED_PAIRS_REGEX: Regex =
+        Regex::new("(?<seed_start>\\d+) (?<seed_range>\\d+)").unwrap();
+}
+
+/// <https://adventofcode.com/2023/day/5>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 5,
+    title: "If You Give A Seed A Fertilizer",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &[],
+    part_1: None,
+    part_2: Some(|input| handle_part_2(input).to_string()),
+};
+
+/// Maps from seeds to locations, in the order they apply
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Seed ranges and the maps, by name
+fn parse_almanac(input: &str) -> (Vec<Range<usize>>, HashMap<String, RangeMap<usize>>) {
+    let mut maps: HashMap<String, RangeMap<usize>> = HashMap::new();
+
+    for m in INPUT_MAP_REGEX.captures_iter(input) {
+        let map_name = m.name("map_name").unwrap().as_str();
+
+        let map = maps.entry(map_name.to_owned()).or_default();
+
+        for line in m.name("map_values").unwrap().as_str().lines() {
+            let values: Vec<usize> = line
+                .split(" ")
+                .take(3)
+                .map(|v| v.parse().unwrap())
+                .collect();
+
+            let (destination_start, source_start, range) = (values[0], values[1], values[2]);
+
+            map.insert(Range::with_len(source_start, range), destination_start);
+        }
+    }
+
+    let seeds: Vec<Range<usize>> = SEED_PAIRS_REGEX
+        .captures_iter(input.lines().next().unwrap()) // only use the first line for the seeds
+        .map(|m| {
+            let seed_start: usize = m.name("seed_start").unwrap().as_str().parse().unwrap();
+            let seed_range: usize = m.name("seed_range").unwrap().as_str().parse().unwrap();
+
+            Range::with_len(seed_start, seed_range)
+        })
+        .collect();
+
+    (seeds, maps)
+}
+
+/// Ranges obtained after each map of the chain, whole ranges of seeds being mapped at once. The
+/// merged ranges are sorted by start.
+fn map_chain(
+    seeds: Vec<Range<usize>>,
+    maps: &HashMap<String, RangeMap<usize>>,
+) -> Vec<(&'static str, Vec<Range<usize>>)> {
+    MAPS.iter()
+        .scan(seeds, |ranges, name| {
+            *ranges = maps[*name].map_ranges(ranges.clone());
+
+            Some((*name, ranges.clone()))
+        })
+        .collect()
+}
+
+fn handle_part_2(input: &str) -> usize {
+    let (seeds, maps) = phase::parse(|| parse_almanac(input));
+
+    let (_, locations) = phase::solve(|| map_chain(seeds, &maps).pop().unwrap());
+
+    locations[0].start
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::{assert_debug_snapshot, example};
+
+    use crate::{handle_part_2, map_chain, parse_almanac};
+
+    const EXAMPLE: &str = "
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+
+        water-to-light map:
+        88 18 7
+        18 25 70
+
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        ";
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(handle_part_2(&example!(EXAMPLE)), 46);
+    }
+
+    #[test]
+    fn test_map_chain() {
+        let (seeds, maps) = parse_almanac(&example!(EXAMPLE));
+
+        assert_debug_snapshot!("map_chain", map_chain(seeds, &maps));
+    }
+}
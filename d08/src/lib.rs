@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use helpers::{lazy_static, math::crt, phase, Day, Regex};
+
+lazy_static! {
+    static ref DIRECTIONS_REGEX: Regex = Regex::new("[RL]+").unwrap();
+    static ref NODE_REGEX: Regex = Regex::new(
+        "(?<source_node>[A-Z\\d]{3}) = \\((?<left_node>[A-Z\\d]{3}), (?<right_node>[A-Z\\d]{3})\\)"
+    )
+    .unwrap();
+}
+
+/// <https://adventofcode.com/2023/day/8>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 8,
+    title: "Haunted Wasteland",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &[],
+    part_1: Some(|input| handle(input).to_string()),
+    part_2: Some(|input| handle_ghosts(input).to_string()),
+};
+
+fn handle(input: &str) -> u64 {
+    let (map, directions) = phase::parse(|| {
+        let map: HashMap<&str, Node> = NODE_REGEX
+            .captures_iter(input)
+            .map(|c| {
+                let source_node = c.name("source_node").unwrap().as_str();
+                let left = c.name("left_node").unwrap().as_str();
+                let right = c.name("right_node").unwrap().as_str();
+
+                let node_type = match source_node {
+                    "AAA" => NodeType::Start,
+                    "ZZZ" => NodeType::End,
+                    _ => NodeType::Default,
+                };
+
+                (
+                    source_node,
+                    Node {
+                        left,
+                        right,
+                        node_type,
+                    },
+                )
+            })
+            .collect();
+
+        let directions: Vec<Direction> = DIRECTIONS_REGEX
+            .find(input.lines().next().unwrap()) // only take the first line
+            .unwrap()
+            .as_str()
+            .chars()
+            .map(|c| match c {
+                'R' => Direction::Right,
+                'L' => Direction::Left,
+                // we are safe because of the regex
+                _ => panic!(),
+            })
+            .collect();
+
+        (map, directions)
+    });
+
+    phase::solve(|| get_node_counter(&map, &directions, map.get("AAA").unwrap()))
+}
+
+fn get_node_counter(map: &HashMap<&str, Node>, directions: &[Direction], start_node: &Node) -> u64 {
+    let mut current_node = start_node;
+
+    let mut counter = 0;
+
+    for direction in directions.iter().cycle() {
+        current_node = match direction {
+            Direction::Left => map.get(current_node.left).unwrap(),
+            Direction::Right => map.get(current_node.right).unwrap(),
+        };
+        counter += 1;
+
+        if current_node.node_type == NodeType::End {
+            break;
+        }
+    }
+
+    counter
+}
+
+fn handle_ghosts(input: &str) -> u64 {
+    let (map, directions, start_nodes) = phase::parse(|| {
+        let mut start_nodes: Vec<&str> = vec![];
+
+        let map: HashMap<&str, Node> = NODE_REGEX
+            .captures_iter(input)
+            .map(|c| {
+                let source_node = c.name("source_node").unwrap().as_str();
+                let left = c.name("left_node").unwrap().as_str();
+                let right = c.name("right_node").unwrap().as_str();
+
+                let node_type = match source_node.chars().last().unwrap() {
+                    'A' => NodeType::Start,
+                    'Z' => NodeType::End,
+                    _ => NodeType::Default,
+                };
+
+                let node = Node {
+                    left,
+                    right,
+                    node_type,
+                };
+
+                if node.node_type == NodeType::Start {
+                    start_nodes.push(source_node);
+                }
+
+                (source_node, node)
+            })
+            .collect();
+
+        let directions: Vec<Direction> = DIRECTIONS_REGEX
+            .find(input.lines().next().unwrap()) // only take the first line
+            .unwrap()
+            .as_str()
+            .chars()
+            .map(|c| match c {
+                'R' => Direction::Right,
+                'L' => Direction::Left,
+                // we are safe because of the regex
+                _ => panic!(),
+            })
+            .collect();
+
+        (map, directions, start_nodes)
+    });
+
+    phase::solve(|| ghost_steps(&map, &directions, &start_nodes))
+}
+
+/// Steps until every ghost is on an end node at once
+fn ghost_steps(map: &HashMap<&str, Node>, directions: &[Direction], start_nodes: &[&str]) -> u64 {
+    let cycles: Vec<(u64, u64)> = start_nodes
+        .iter()
+        .map(|start_node| get_cycle(map, directions, start_node))
+        .collect();
+
+    // all ghosts are on an end node at steps matching every first end step modulo its cycle
+    let (steps, period) = crt(cycles
+        .iter()
+        .map(|(first, length)| (*first as i64, *length as i64)))
+    .unwrap();
+
+    let (steps, period) = (steps as u64, period as u64);
+
+    // the solution only counts once every ghost has reached its cycle
+    let earliest = cycles.iter().map(|(first, _)| *first).max().unwrap();
+
+    if steps < earliest {
+        steps + (earliest - steps).div_ceil(period) * period
+    } else {
+        steps
+    }
+}
+
+/// Steps to reach an end node for the first time, and steps between the next visits of an end
+/// node.
+///
+/// The ghost loops once it is back on a node at the same position in the directions. Its visits
+/// of end nodes only follow such a period if they all happen in the loop, evenly spaced, which
+/// the puzzle inputs guarantee.
+fn get_cycle(map: &HashMap<&str, Node>, directions: &[Direction], start_node: &str) -> (u64, u64) {
+    let mut current_node = start_node;
+
+    // first step at which each node was reached at each position in the directions
+    let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+    let mut end_steps = vec![];
+
+    for (step, (position, direction)) in directions.iter().enumerate().cycle().enumerate() {
+        if let Some(&loop_start) = seen.get(&(current_node, position)) {
+            let length = step as u64 - loop_start;
+            // the visit of this step is the first one of the next loop
+            let end_steps = &end_steps[..end_steps.partition_point(|s| *s < step as u64)];
+            let period = length / end_steps.len().max(1) as u64;
+
+            assert!(
+                !end_steps.is_empty()
+                    && end_steps[0] >= loop_start
+                    && (0..)
+                        .zip(end_steps)
+                        .all(|(i, s)| *s == end_steps[0] + i * period)
+                    && end_steps.len() as u64 * period == length,
+                "the ghost of {start_node} reaches an end node at steps {end_steps:?}, looping \
+                 from step {loop_start} every {length} steps, instead of periodically",
+            );
+
+            return (end_steps[0], period);
+        }
+
+        seen.insert((current_node, position), step as u64);
+
+        let node = map.get(current_node).unwrap();
+
+        current_node = match direction {
+            Direction::Left => node.left,
+            Direction::Right => node.right,
+        };
+
+        if map.get(current_node).unwrap().node_type == NodeType::End {
+            end_steps.push(step as u64 + 1);
+        }
+    }
+
+    unreachable!("the directions are not empty")
+}
+
+#[derive(Debug)]
+struct Node<'a> {
+    left: &'a str,
+    right: &'a str,
+    node_type: NodeType,
+}
+
+#[derive(Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq)]
+enum NodeType {
+    Start,
+    End,
+    Default,
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::example;
+
+    use crate::{handle, handle_ghosts};
+
+    #[test]
+    fn test_handle_1() {
+        let input = example! {"
+            RL
+
+            AAA = (BBB, CCC)
+            BBB = (DDD, EEE)
+            CCC = (ZZZ, GGG)
+            DDD = (DDD, DDD)
+            EEE = (EEE, EEE)
+            GGG = (GGG, GGG)
+            ZZZ = (ZZZ, ZZZ)
+            "};
+
+        let res = handle(&input);
+
+        assert_eq!(res, 2)
+    }
+
+    #[test]
+    fn test_handle_2() {
+        let input = example! {"
+            LLR
+
+            AAA = (BBB, BBB)
+            BBB = (AAA, ZZZ)
+            ZZZ = (ZZZ, ZZZ)
+            "};
+
+        let res = handle(&input);
+
+        assert_eq!(res, 6)
+    }
+
+    #[test]
+    fn test_handle_ghost() {
+        let input = example! {"
+            LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)
+            11Z = (11B, XXX)
+            22A = (22B, XXX)
+            22B = (22C, 22C)
+            22C = (22Z, 22Z)
+            22Z = (22B, 22B)
+            XXX = (XXX, XXX)
+            "};
+
+        let res = handle_ghosts(&input);
+
+        assert_eq!(res, 6);
+
+        // the ghosts first reach an end node after 1 and 2 steps, then every 2 and 3 steps, so
+        // the lcm of their first end steps, 2, is not a solution
+        let input = example! {"
+            L
+
+            11A = (11Z, 11Z)
+            11Z = (11B, 11B)
+            11B = (11Z, 11Z)
+            33A = (33B, 33B)
+            33B = (33Z, 33Z)
+            33Z = (33C, 33C)
+            33C = (33D, 33D)
+            33D = (33Z, 33Z)
+            "};
+
+        let res = handle_ghosts(&input);
+
+        assert_eq!(res, 5)
+    }
+
+    #[test]
+    #[should_panic(expected = "instead of periodically")]
+    fn test_handle_ghost_uneven_ends() {
+        // 11Z and 12Z are reached after 1 and 2 steps, then every 3 steps
+        let input = example! {"
+            L
+
+            11A = (11Z, 11Z)
+            11Z = (12Z, 12Z)
+            12Z = (11B, 11B)
+            11B = (11Z, 11Z)
+            "};
+
+        handle_ghosts(&input);
+    }
+}
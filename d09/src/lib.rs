@@ -0,0 +1,159 @@
+use std::fmt;
+
+use helpers::{lazy_static, phase, Day, Regex};
+use itertools::Itertools;
+
+lazy_static! {
+    static ref SENSOR_REGEX: Regex = Regex::new("-?\\d+").unwrap();
+}
+
+/// <https://adventofcode.com/2023/day/9>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 9,
+    title: "Mirage Maintenance",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &[],
+    part_1: Some(|input| handle_part_1(input).to_string()),
+    part_2: Some(|input| handle_part_2(input).to_string()),
+};
+
+/// The values of a sensor and their successive differences, down to a row of zeros
+pub struct Pyramid(Vec<Vec<i64>>);
+
+impl Pyramid {
+    /// Pyramid of a line of the report
+    pub fn new(line: &str) -> Self {
+        Self::from_values(parse_values(line))
+    }
+
+    pub fn from_values(values: Vec<i64>) -> Self {
+        let mut rows = vec![values];
+
+        while rows.last().unwrap().iter().any(|v| *v != 0) {
+            let diffs = rows
+                .last()
+                .unwrap()
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| *b - *a)
+                .collect();
+
+            rows.push(diffs);
+        }
+
+        Self(rows)
+    }
+
+    /// Next value of the sensor, each row growing by the last value of the row below
+    pub fn extrapolate(&self) -> i64 {
+        self.0.iter().map(|row| *row.last().unwrap()).sum()
+    }
+
+    /// Previous value of the sensor, each row growing backwards by the first value of the row
+    /// below
+    pub fn extrapolate_backwards(&self) -> i64 {
+        self.0
+            .iter()
+            .rev()
+            .map(|row| *row.first().unwrap())
+            .reduce(|acc, e| e - acc)
+            .unwrap()
+    }
+}
+
+/// Each row is shifted right by half a cell, as drawn in the puzzle
+impl fmt::Display for Pyramid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self
+            .0
+            .iter()
+            .flatten()
+            .map(|v| v.to_string().len())
+            .max()
+            .unwrap_or(0);
+        // at least one space between values, and an even width to shift rows by half of it
+        let width = (len + 2) & !1;
+
+        for (depth, row) in self.0.iter().enumerate() {
+            write!(f, "{}", " ".repeat(depth * width / 2))?;
+
+            for value in row {
+                write!(f, "{value:>width$}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Values of a line of the report
+fn parse_values(line: &str) -> Vec<i64> {
+    SENSOR_REGEX
+        .find_iter(line)
+        .map(|m| m.as_str().parse().unwrap())
+        .collect()
+}
+
+/// Sum of a prediction of every history of the report
+fn handle(input: &str, predict: fn(&Pyramid) -> i64) -> i64 {
+    let histories: Vec<Vec<i64>> = phase::parse(|| input.lines().map(parse_values).collect());
+
+    phase::solve(|| {
+        histories
+            .into_iter()
+            .map(|values| predict(&Pyramid::from_values(values)))
+            .sum()
+    })
+}
+
+fn handle_part_1(input: &str) -> i64 {
+    handle(input, Pyramid::extrapolate)
+}
+
+fn handle_part_2(input: &str) -> i64 {
+    handle(input, Pyramid::extrapolate_backwards)
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::{assert_snapshot, example};
+
+    use crate::{handle_part_1, handle_part_2, Pyramid};
+
+    #[test]
+    fn test_part_1() {
+        let input = example! {"
+            0 3 6 9 12 15
+            1 3 6 10 15 21
+            10 13 16 21 30 45
+            "};
+
+        assert_eq!(handle_part_1(&input), 114);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = example! {"
+            0 3 6 9 12 15
+            1 3 6 10 15 21
+            10 13 16 21 30 45
+            "};
+
+        assert_eq!(handle_part_2(&input), 2);
+    }
+
+    #[test]
+    fn test_part_2_lines() {
+        assert_eq!(Pyramid::new("10 13 16 21 30 45").extrapolate_backwards(), 5);
+        // assert_eq!(handle_part_2_line("0 3 6 9 12 15"), -3);
+        // assert_eq!(handle_part_2_line("1 3 6 10 15 21"), 0);
+    }
+
+    #[test]
+    fn test_pyramid() {
+        assert_snapshot!("pyramid", Pyramid::new("10 13 16 21 30 45"));
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+
+use helpers::{
+    geometry::interior_points,
+    graph::{bfs, dfs},
+    phase, Day,
+};
+
+/// <https://adventofcode.com/2023/day/10>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 10,
+    title: "Pipe Maze",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &[],
+    part_1: Some(|input| handle_part_1(input).to_string()),
+    part_2: Some(|input| handle_part_2(input).to_string()),
+};
+
+#[derive(PartialEq, Debug)]
+enum TileType {
+    Start,
+    Ground,
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+#[derive(Debug)]
+struct Tile {
+    tile_type: TileType,
+    row: usize,
+    column: usize,
+}
+
+impl Tile {
+    fn is_heading_north(&self) -> bool {
+        matches!(
+            self.tile_type,
+            TileType::Vertical | TileType::NorthWest | TileType::NorthEast
+        )
+    }
+
+    fn is_heading_south(&self) -> bool {
+        matches!(
+            self.tile_type,
+            TileType::Vertical | TileType::SouthEast | TileType::SouthWest
+        )
+    }
+
+    fn is_heading_west(&self) -> bool {
+        matches!(
+            self.tile_type,
+            TileType::Horizontal | TileType::NorthWest | TileType::SouthWest
+        )
+    }
+}
+
+/// Parses the maze, replacing the start with the tile it stands on, and returns the start position
+fn parse_maze(input: &str) -> (Vec<Vec<Tile>>, (usize, usize)) {
+    let mut start = None;
+
+    let mut maze: Vec<Vec<Tile>> = input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.char_indices()
+                .map(|(column, c)| {
+                    let tile_type = match c {
+                        '|' => TileType::Vertical,
+                        '-' => TileType::Horizontal,
+                        'L' => TileType::NorthEast,
+                        'J' => TileType::NorthWest,
+                        '7' => TileType::SouthWest,
+                        'F' => TileType::SouthEast,
+                        '.' => TileType::Ground,
+                        'S' => TileType::Start,
+                        _ => panic!(),
+                    };
+
+                    let tile = Tile {
+                        tile_type,
+                        row,
+                        column,
+                    };
+
+                    if tile.tile_type == TileType::Start {
+                        start = Some((tile.row, tile.column));
+                    }
+
+                    tile
+                })
+                .collect()
+        })
+        .collect();
+
+    // transform the start
+
+    let (start_row, start_column) = start.unwrap();
+
+    let is_north = start_row > 0
+        && maze
+            .get(start_row - 1)
+            .map(|row| row[start_column].is_heading_south())
+            .unwrap_or(false);
+
+    let is_south = maze
+        .get(start_row + 1)
+        .map(|row| row[start_column].is_heading_north())
+        .unwrap_or(false);
+
+    let is_east = maze[start_row]
+        .get(start_column + 1)
+        .map(|v| v.is_heading_west())
+        .unwrap_or(false);
+
+    let start_type = if is_north {
+        if is_south {
+            TileType::Vertical
+        } else if is_east {
+            TileType::NorthEast
+        } else {
+            TileType::NorthWest
+        }
+    } else if is_east {
+        if is_south {
+            TileType::SouthEast
+        } else {
+            TileType::Horizontal
+        }
+    } else {
+        TileType::SouthWest
+    };
+
+    maze[start_row][start_column].tile_type = start_type;
+
+    (maze, (start_row, start_column))
+}
+
+/// The two tiles a tile of the loop connects
+fn next_tiles(maze: &[Vec<Tile>], &(row, column): &(usize, usize)) -> [(usize, usize); 2] {
+    match maze[row][column].tile_type {
+        TileType::Start => panic!(), // we have replaced it
+        TileType::Vertical => [(row + 1, column), (row - 1, column)],
+        TileType::Horizontal => [(row, column + 1), (row, column - 1)],
+        TileType::NorthEast => [(row - 1, column), (row, column + 1)],
+        TileType::NorthWest => [(row - 1, column), (row, column - 1)],
+        TileType::SouthEast => [(row + 1, column), (row, column + 1)],
+        TileType::SouthWest => [(row + 1, column), (row, column - 1)],
+        TileType::Ground => panic!(), // we should not arrive on ground
+    }
+}
+
+/// Distance of each tile of the loop from the start, `None` for the other tiles
+struct Distances(Vec<Vec<Option<usize>>>);
+
+impl Distances {
+    fn new(maze: &[Vec<Tile>], start: (usize, usize)) -> Self {
+        let mut distances = vec![vec![None; maze[0].len()]; maze.len()];
+
+        for ((row, column), distance) in bfs(start, |position| next_tiles(maze, position)) {
+            distances[row][column] = Some(distance);
+        }
+
+        Self(distances)
+    }
+
+    fn max(&self) -> usize {
+        self.0.iter().flatten().flatten().copied().max().unwrap()
+    }
+}
+
+/// Tiles out of the loop are drawn as `.`, as in the puzzle
+impl fmt::Display for Distances {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.max().to_string().len();
+
+        for row in &self.0 {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|distance| match distance {
+                    Some(distance) => format!("{distance:>width$}"),
+                    None => format!("{:>width$}", "."),
+                })
+                .collect();
+
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn handle_part_1(input: &str) -> u64 {
+    let (maze, start) = phase::parse(|| parse_maze(input));
+
+    // the farthest tile is the last one reached going both ways around the loop
+    phase::solve(|| Distances::new(&maze, start).max() as u64)
+}
+
+fn handle_part_2(input: &str) -> u64 {
+    let (maze, start) = phase::parse(|| parse_maze(input));
+
+    phase::solve(|| {
+        // going depth first follows the loop in one direction, giving the vertices of a polygon
+        let vertices: Vec<(i64, i64)> = dfs(start, |position| next_tiles(&maze, position))
+            .map(|(row, column)| (column as i64, row as i64))
+            .collect();
+
+        // tiles are lattice points, the enclosed ones are strictly inside the polygon
+        interior_points(&vertices).expect("the loop overlaps itself")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::{assert_snapshot, example};
+
+    use crate::{handle_part_1, handle_part_2, parse_maze, Distances};
+
+    #[test]
+    fn test_part_1() {
+        let input = example! {"
+        .....
+        .S-7.
+        .|.|.
+        .L-J.
+        .....
+        "};
+
+        assert_eq!(handle_part_1(&input), 4);
+
+        // Not handling edge case for start on the edge
+
+        // let input = "..F7.
+        // .FJ|.
+        // SJ.L7
+        // |F--J
+        // LJ...";
+
+        // assert_eq!(handle_part_1(input), 8);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = example! {"
+        .....
+        .S-7.
+        .|.|.
+        .L-J.
+        .....
+        "};
+
+        assert_eq!(handle_part_2(&input), 1);
+
+        let input = example! {"
+        ...........
+        .S-------7.
+        .|F-----7|.
+        .||.....||.
+        .||.....||.
+        .|L-7.F-J|.
+        .|..|.|..|.
+        .L--J.L--J.
+        ...........
+        "};
+
+        assert_eq!(handle_part_2(&input), 4);
+
+        let input = example! {"
+        .F----7F7F7F7F-7....
+        .|F--7||||||||FJ....
+        .||.FJ||||||||L7....
+        FJL7L7LJLJ||LJ.L-7..
+        L--J.L7...LJS7F-7L7.
+        ....F-J..F7FJ|L7L7L7
+        ....L7.F7||L7|.L7L7|
+        .....|FJLJ|FJ|F7|.LJ
+        ....FJL-7.||.||||...
+        ....L---J.LJ.LJLJ...
+        "};
+
+        assert_eq!(handle_part_2(&input), 8);
+
+        let input = example! {"
+        FF7FSF7F7F7F7F7F---7
+        L|LJ||||||||||||F--J
+        FL-7LJLJ||||||LJL-77
+        F--JF--7||LJLJ7F7FJ-
+        L---JF-JLJ.||-FJLJJ7
+        |F|F-JF---7F7-L7L|7|
+        |FFJF7L7F-JF7|JL---7
+        7-L-JL7||F7|L7F-7F7|
+        L.L7LFJ|||||FJL7||LJ
+        L7JLJL-JLJLJL--JLJ.L
+        "};
+
+        assert_eq!(handle_part_2(&input), 10);
+    }
+
+    #[test]
+    fn test_distances() {
+        let input = example! {"
+        ..F7.
+        .FJ|.
+        SJ.L7
+        |F--J
+        LJ...
+        "};
+
+        let (maze, start) = parse_maze(&input);
+
+        assert_snapshot!("distances", Distances::new(&maze, start));
+    }
+}
@@ -0,0 +1,150 @@
+use helpers::{bitset::BitSet, param, phase, Day};
+use itertools::Itertools;
+
+/// <https://adventofcode.com/2023/day/11>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 11,
+    title: "Cosmic Expansion",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &["expansion"],
+    part_1: Some(|input| galaxy_distances(input, 2).to_string()),
+    part_2: Some(|input| galaxy_distances(input, param("expansion", 1000000)).to_string()),
+};
+
+/// Sum of the distances between every pair of galaxies of the image, once each empty row and
+/// column is replaced by `expand_size` of them
+pub fn galaxy_distances(input: &str, expand_size: usize) -> usize {
+    let (galaxies, rows_with_galaxies, cols_with_galaxies) = phase::parse(|| parse_image(input));
+
+    phase::solve(|| {
+        sum_distances(
+            galaxies,
+            &rows_with_galaxies,
+            &cols_with_galaxies,
+            expand_size,
+        )
+    })
+}
+
+/// Positions of the galaxies, and the rows and the columns holding at least one of them
+fn parse_image(input: &str) -> (Vec<(usize, usize)>, BitSet, BitSet) {
+    let (rows_count, cols_count) = (
+        input.lines().count(),
+        input.lines().next().unwrap().chars().count(),
+    );
+
+    let mut rows_with_galaxies = BitSet::with_capacity(rows_count);
+    let mut cols_with_galaxies = BitSet::with_capacity(cols_count);
+
+    let mut galaxies = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        for (j, char) in line.char_indices() {
+            if char == '#' {
+                galaxies.push((i, j));
+                rows_with_galaxies.insert(i);
+                cols_with_galaxies.insert(j);
+            }
+        }
+    }
+
+    (galaxies, rows_with_galaxies, cols_with_galaxies)
+}
+
+fn sum_distances(
+    galaxies: Vec<(usize, usize)>,
+    rows_with_galaxies: &BitSet,
+    cols_with_galaxies: &BitSet,
+    expand_size: usize,
+) -> usize {
+    let mut res = 0;
+
+    // number of empty lines in a range, from the number of lines with galaxies before each end
+    let empty_count = |with_galaxies: &BitSet, range: [usize; 2]| {
+        range[1] - range[0] - (with_galaxies.rank(range[1]) - with_galaxies.rank(range[0]))
+    };
+
+    for ((a_i, a_j), (b_i, b_j)) in galaxies.into_iter().tuple_combinations() {
+        let mut rows = [a_i, b_i];
+        let mut cols = [a_j, b_j];
+
+        rows.sort();
+        cols.sort();
+
+        let empty_rows_count = empty_count(rows_with_galaxies, rows);
+        let empty_cols_count = empty_count(cols_with_galaxies, cols);
+
+        res += rows[1] - rows[0] - empty_rows_count + expand_size * empty_rows_count;
+        res += cols[1] - cols[0] - empty_cols_count + expand_size * empty_cols_count;
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::example;
+
+    use crate::{galaxy_distances, DAY};
+
+    #[test]
+    fn test_part_1() {
+        let input = example! {"
+        ...#......
+        .......#..
+        #.........
+        ..........
+        ......#...
+        .#........
+        .........#
+        ..........
+        .......#..
+        #...#.....
+        "};
+
+        assert_eq!(galaxy_distances(&input, 2), 374);
+
+        // part 1 doubles the empty lines, a factor of 1 would leave the image as is and give 292
+        assert_eq!((DAY.part_1.unwrap())(&input), "374");
+        assert_eq!(galaxy_distances(&input, 1), 292);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = example! {"
+        ...#......
+        .......#..
+        #.........
+        ..........
+        ......#...
+        .#........
+        .........#
+        ..........
+        .......#..
+        #...#.....
+        "};
+
+        assert_eq!(galaxy_distances(&input, 10), 1030);
+    }
+
+    #[test]
+    fn test_part_2_bis() {
+        let input = example! {"
+        ...#......
+        .......#..
+        #.........
+        ..........
+        ......#...
+        .#........
+        .........#
+        ..........
+        .......#..
+        #...#.....
+        "};
+
+        let res = galaxy_distances(&input, 100);
+
+        assert_eq!(res, 8410);
+    }
+}
@@ -0,0 +1,146 @@
+use helpers::{memo::Memo, param, phase, tracing, Day};
+
+/// <https://adventofcode.com/2023/day/12>
+pub const DAY: Day = Day {
+    year: 2023,
+    day: 12,
+    title: "Hot Springs",
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    params: &["unfold"],
+    part_1: Some(|input| handle_part_1(input).to_string()),
+    part_2: Some(|input| handle_part_2(input).to_string()),
+};
+
+/// Springs and checksums of the line once unfolded `unfold` times
+fn parse_line(line: &str, unfold: usize) -> (String, Vec<usize>) {
+    let (springs, checksums) = line.split_once(' ').unwrap();
+
+    let springs = vec![springs; unfold].join("?");
+    let checksums = vec![checksums; unfold].join(",");
+
+    let checksums: Vec<usize> = checksums.split(",").map(|c| c.parse().unwrap()).collect();
+
+    (springs, checksums)
+}
+
+/// Number of arrangements of a parsed line
+fn arrangements(springs: &str, checksums: &[usize]) -> usize {
+    let mut memo = Memo::new();
+    let arrangements = count_arrangements(&mut memo, springs.as_bytes(), checksums);
+
+    tracing::debug!("{}", memo.stats());
+
+    arrangements
+}
+
+/// Counts the ways to replace the unknown springs so that the groups of broken springs match the
+/// checksums. Suffixes are cached by their lengths, which identify them within a line.
+fn count_arrangements(
+    memo: &mut Memo<(usize, usize), usize>,
+    springs: &[u8],
+    checksums: &[usize],
+) -> usize {
+    memo.get_or_insert_with((springs.len(), checksums.len()), |memo| {
+        let Some(&spring) = springs.first() else {
+            // every group must have been placed
+            return checksums.is_empty() as usize;
+        };
+
+        let mut res = 0;
+
+        // the spring is operational
+        if spring != b'#' {
+            res += count_arrangements(memo, &springs[1..], checksums);
+        }
+
+        // the spring starts the next group of broken springs, which must be followed by an
+        // operational spring or the end of the line
+        if spring != b'.' {
+            if let Some((&group, checksums)) = checksums.split_first() {
+                let fits = springs.len() >= group
+                    && !springs[..group].contains(&b'.')
+                    && springs.get(group) != Some(&b'#');
+
+                if fits {
+                    let rest = springs.get(group + 1..).unwrap_or_default();
+
+                    res += count_arrangements(memo, rest, checksums);
+                }
+            }
+        }
+
+        res
+    })
+}
+
+fn handle(input: &str, unfold: usize) -> usize {
+    let lines: Vec<(String, Vec<usize>)> =
+        phase::parse(|| input.lines().map(|line| parse_line(line, unfold)).collect());
+
+    phase::solve(|| {
+        lines
+            .iter()
+            .map(|(springs, checksums)| arrangements(springs, checksums))
+            .sum()
+    })
+}
+
+fn handle_part_1(input: &str) -> usize {
+    handle(input, 1)
+}
+
+fn handle_part_2(input: &str) -> usize {
+    handle(input, param("unfold", 5))
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::example;
+
+    use crate::{arrangements, handle_part_1, handle_part_2, parse_line};
+
+    /// Number of arrangements of the line once unfolded `unfold` times
+    fn handle_line(line: &str, unfold: usize) -> usize {
+        let (springs, checksums) = parse_line(line, unfold);
+
+        arrangements(&springs, &checksums)
+    }
+
+    #[test]
+    fn test_line() {
+        assert_eq!(handle_line("???.### 1,1,3", 1), 1);
+        assert_eq!(handle_line(".??..??...?##. 1,1,3", 1), 4);
+        assert_eq!(handle_line("?#?#?#?#?#?#?#? 1,3,1,6", 1), 1);
+        assert_eq!(handle_line("????.#...#... 4,1,1", 1), 1);
+        assert_eq!(handle_line("????.######..#####. 1,6,5", 1), 4);
+        assert_eq!(handle_line("?###???????? 3,2,1", 1), 10);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = example! {"
+        ???.### 1,1,3
+        .??..??...?##. 1,1,3
+        ?#?#?#?#?#?#?#? 1,3,1,6
+        ????.#...#... 4,1,1
+        ????.######..#####. 1,6,5
+        ?###???????? 3,2,1
+        "};
+
+        assert_eq!(handle_part_1(&input), 21);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = example! {"
+        ???.### 1,1,3
+        .??..??...?##. 1,1,3
+        ?#?#?#?#?#?#?#? 1,3,1,6
+        ????.#...#... 4,1,1
+        ????.######..#####. 1,6,5
+        ?###???????? 3,2,1
+        "};
+
+        assert_eq!(handle_part_2(&input), 525152);
+    }
+}
@@ -0,0 +1,16 @@
+//! Generates the C header of the library into `OUT_DIR`. The copy kept in the repository for the
+//! tools linking against the library, `include/aoc.h`, is checked against it by the tests.
+
+use std::{env, path::Path};
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    cbindgen::generate(&crate_dir)
+        .expect("cannot generate the C header")
+        .write_to_file(Path::new(&out_dir).join("aoc.h"));
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
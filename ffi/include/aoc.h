@@ -0,0 +1,51 @@
+/* Generated by the build of the aoc-ffi crate from src/lib.rs, do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The answer was written to the output buffer
+#define AOC_OK 0
+
+// The day or the part is not implemented
+#define AOC_NOT_IMPLEMENTED 1
+
+// A pointer is null while its length is not 0
+#define AOC_NULL_POINTER 2
+
+// The input is not valid UTF-8
+#define AOC_INVALID_INPUT 3
+
+// The output buffer cannot hold the answer, whose length is written to `out_len`
+#define AOC_BUFFER_TOO_SMALL 4
+
+// The solver panicked, most likely on an invalid input
+#define AOC_PANIC 5
+
+// The configuration of the workspace cannot be loaded
+#define AOC_INVALID_CONFIG 6
+
+// Solves a part of a day, with the parameters of the workspace configuration.
+//
+// `out_len` holds the capacity of `out_buf`, and receives the length of the answer written to
+// it, which is not NUL terminated. On failure, a message describing the error is written
+// instead, truncated to fit, except for `AOC_BUFFER_TOO_SMALL`.
+//
+// # Safety
+//
+// `input_ptr` must point to `input_len` readable bytes and `out_buf` to `*out_len` writable
+// bytes, either pointer being allowed to be null when its length is 0.
+int32_t aoc_solve(uint16_t year,
+                  uint8_t day,
+                  uint8_t part,
+                  const uint8_t *input_ptr,
+                  size_t input_len,
+                  uint8_t *out_buf,
+                  size_t *out_len);
+
+#endif /* AOC_H */
@@ -0,0 +1,203 @@
+//! C ABI of the solvers, for the tools of other languages. The build generates the C header in
+//! `include/aoc.h`, and `tests/test_aoc.c` shows how to call the library.
+//!
+//! A panic unwinding out of an `extern "C"` function aborts the process, so every panic past the
+//! checks of the pointers, those of the solvers included, is caught and reported as
+//! [`AOC_PANIC`].
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    slice, str,
+};
+
+use helpers::config::Config;
+use runner::{find_day, panic_message};
+
+/// The answer was written to the output buffer
+pub const AOC_OK: i32 = 0;
+/// The day or the part is not implemented
+pub const AOC_NOT_IMPLEMENTED: i32 = 1;
+/// A pointer is null while its length is not 0
+pub const AOC_NULL_POINTER: i32 = 2;
+/// The input is not valid UTF-8
+pub const AOC_INVALID_INPUT: i32 = 3;
+/// The output buffer cannot hold the answer, whose length is written to `out_len`
+pub const AOC_BUFFER_TOO_SMALL: i32 = 4;
+/// The solver panicked, most likely on an invalid input
+pub const AOC_PANIC: i32 = 5;
+/// The configuration of the workspace cannot be loaded
+pub const AOC_INVALID_CONFIG: i32 = 6;
+
+/// Solves a part of a day, with the parameters of the workspace configuration.
+///
+/// `out_len` holds the capacity of `out_buf`, and receives the length of the answer written to
+/// it, which is not NUL terminated. On failure, a message describing the error is written
+/// instead, truncated to fit, except for `AOC_BUFFER_TOO_SMALL`.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes and `out_buf` to `*out_len` writable
+/// bytes, either pointer being allowed to be null when its length is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if out_len.is_null()
+        || (out_buf.is_null() && *out_len > 0)
+        || (input_ptr.is_null() && input_len > 0)
+    {
+        return AOC_NULL_POINTER;
+    }
+
+    let input = match input_len {
+        0 => &[],
+        _ => slice::from_raw_parts(input_ptr, input_len),
+    };
+
+    let out = match *out_len {
+        0 => &mut [],
+        _ => slice::from_raw_parts_mut(out_buf, *out_len),
+    };
+
+    let (status, text) = panic::catch_unwind(|| solve(year, day, part, input))
+        .unwrap_or_else(|payload| (AOC_PANIC, panic_message(payload.as_ref())));
+
+    match panic::catch_unwind(AssertUnwindSafe(|| write(out, status, &text))) {
+        Ok(Some(written)) => {
+            *out_len = written;
+            status
+        }
+        Ok(None) => {
+            *out_len = text.len();
+            AOC_BUFFER_TOO_SMALL
+        }
+        Err(_) => {
+            *out_len = 0;
+            AOC_PANIC
+        }
+    }
+}
+
+/// Status of the part, with its answer or the description of the error
+fn solve(year: u16, day: u8, part: u8, input: &[u8]) -> (i32, String) {
+    let Some(day) = find_day(year, day) else {
+        return (
+            AOC_NOT_IMPLEMENTED,
+            format!("day {day} of {year} is not implemented"),
+        );
+    };
+
+    let input = match str::from_utf8(input) {
+        Ok(input) => input,
+        Err(e) => return (AOC_INVALID_INPUT, format!("invalid input: {e}")),
+    };
+
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => return (AOC_INVALID_CONFIG, format!("invalid configuration: {e}")),
+    };
+
+    match day.solve(part, input, &config) {
+        Some(answer) => (AOC_OK, answer),
+        None => (
+            AOC_NOT_IMPLEMENTED,
+            format!("part {part} of {} is not implemented", day.id()),
+        ),
+    }
+}
+
+/// Copies the answer, or as much of the error as fits, returning the length written. `None` if
+/// the answer does not fit.
+fn write(out: &mut [u8], status: i32, text: &str) -> Option<usize> {
+    let len = match (status, text.len() <= out.len()) {
+        (_, true) => text.len(),
+        (AOC_OK, false) => return None,
+        // cut the message between two characters
+        (_, false) => (0..=out.len())
+            .rev()
+            .find(|&i| text.is_char_boundary(i))
+            .unwrap(),
+    };
+
+    out[..len].copy_from_slice(&text.as_bytes()[..len]);
+
+    Some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use crate::{
+        aoc_solve, AOC_BUFFER_TOO_SMALL, AOC_INVALID_INPUT, AOC_NOT_IMPLEMENTED, AOC_NULL_POINTER,
+        AOC_OK, AOC_PANIC,
+    };
+
+    const REPORT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+    /// Status and output of a call with a buffer of `capacity` bytes
+    fn call(day: u8, part: u8, input: &[u8], capacity: usize) -> (i32, usize, String) {
+        let mut out = vec![0; capacity];
+        let mut len = capacity;
+
+        let status = unsafe {
+            aoc_solve(
+                2023,
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut len,
+            )
+        };
+
+        let text = String::from_utf8_lossy(&out[..len.min(capacity)]).into_owned();
+
+        (status, len, text)
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            call(9, 1, REPORT.as_bytes(), 64),
+            (AOC_OK, 3, "114".to_owned())
+        );
+        assert_eq!(
+            call(9, 2, REPORT.as_bytes(), 1),
+            (AOC_OK, 1, "2".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let (status, len, _) = call(9, 1, REPORT.as_bytes(), 2);
+        assert_eq!((status, len), (AOC_BUFFER_TOO_SMALL, 3));
+
+        let (status, _, message) = call(25, 1, REPORT.as_bytes(), 64);
+        assert_eq!(status, AOC_NOT_IMPLEMENTED);
+        assert_eq!(message, "day 25 of 2023 is not implemented");
+
+        // truncated to the buffer
+        let (status, _, message) = call(9, 3, REPORT.as_bytes(), 9);
+        assert_eq!(
+            (status, message.as_str()),
+            (AOC_NOT_IMPLEMENTED, "part 3 of")
+        );
+
+        assert_eq!(call(9, 1, b"0 3 \xff", 64).0, AOC_INVALID_INPUT);
+
+        // the solver of day 11 panics on an empty input
+        assert_eq!(call(11, 1, b"", 64).0, AOC_PANIC);
+
+        let status =
+            unsafe { aoc_solve(2023, 9, 1, ptr::null(), 3, ptr::null_mut(), ptr::null_mut()) };
+        assert_eq!(status, AOC_NULL_POINTER);
+    }
+}
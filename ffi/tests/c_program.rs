@@ -0,0 +1,57 @@
+//! Checks that the committed header matches the generated one, then builds `test_aoc.c` against
+//! the library and the header, and runs it.
+
+use std::{env, fs, path::Path, process::Command};
+
+/// Header generated by the build script from the current sources
+const GENERATED_HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/aoc.h"));
+
+#[test]
+fn test_header_is_current() {
+    let committed = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+
+    assert!(
+        fs::read_to_string(&committed).unwrap() == GENERATED_HEADER,
+        "{} is stale, update it with: cp {}/aoc.h {}",
+        committed.display(),
+        env!("OUT_DIR"),
+        committed.display()
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn test_c_program() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // the library is built next to the test, in `target/<profile>/deps`
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap();
+    let program = lib_dir.join(format!("test_aoc-{}", std::process::id()));
+
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_owned()))
+        .arg(crate_dir.join("tests/test_aoc.c"))
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-laoc_ffi", "-Wall", "-Werror", "-o"])
+        .arg(&program)
+        .status()
+        .expect("cannot run the C compiler, set CC to one");
+
+    assert!(status.success(), "cannot compile test_aoc.c");
+
+    let output = Command::new(&program)
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .unwrap();
+    let _ = fs::remove_file(&program);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
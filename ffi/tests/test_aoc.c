@@ -0,0 +1,61 @@
+/* Exercises the C ABI of the solvers, built and run by `cargo test -p aoc-ffi`. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static const char REPORT[] = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+static int failures = 0;
+
+static void check(int ok, const char *what) {
+    if (!ok) {
+        fprintf(stderr, "FAIL: %s\n", what);
+        failures++;
+    }
+}
+
+/* Solves with a buffer of `capacity` bytes, returning the status and the NUL terminated output */
+static int solve(uint8_t day, uint8_t part, const char *input, size_t capacity, char *text, size_t *len) {
+    char out[256];
+    *len = capacity;
+
+    int status = aoc_solve(2023, day, part, (const uint8_t *)input, strlen(input), (uint8_t *)out, len);
+
+    size_t written = *len < capacity ? *len : capacity;
+    memcpy(text, out, written);
+    text[written] = '\0';
+
+    return status;
+}
+
+int main(void) {
+    char text[257];
+    size_t len;
+
+    check(solve(9, 1, REPORT, 256, text, &len) == AOC_OK, "day 9 part 1 is solved");
+    check(strcmp(text, "114") == 0 && len == 3, "day 9 part 1 answers 114");
+
+    check(solve(9, 2, REPORT, 256, text, &len) == AOC_OK, "day 9 part 2 is solved");
+    check(strcmp(text, "2") == 0, "day 9 part 2 answers 2");
+
+    check(solve(9, 1, REPORT, 2, text, &len) == AOC_BUFFER_TOO_SMALL, "a short buffer is reported");
+    check(len == 3, "the length of the answer is given when the buffer is too short");
+
+    check(solve(25, 1, REPORT, 256, text, &len) == AOC_NOT_IMPLEMENTED, "day 25 is not implemented");
+    check(strcmp(text, "day 25 of 2023 is not implemented") == 0, "the error is described");
+
+    check(solve(11, 1, "", 256, text, &len) == AOC_PANIC, "a panic is reported, not aborting");
+
+    check(solve(9, 1, "0 3 \xff", 256, text, &len) == AOC_INVALID_INPUT, "invalid UTF-8 is rejected");
+
+    len = 8;
+    check(aoc_solve(2023, 9, 1, NULL, 4, NULL, &len) == AOC_NULL_POINTER, "null pointers are rejected");
+
+    if (failures == 0) {
+        printf("ok\n");
+    }
+
+    return failures == 0 ? 0 : 1;
+}
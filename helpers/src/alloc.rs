@@ -0,0 +1,219 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static HEAP_PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator forwarding to the system one while counting what goes through it.
+///
+/// Opt in from a binary with:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: helpers::alloc::CountingAllocator = helpers::alloc::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+
+    let live = LIVE_BYTES.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+    HEAP_PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        // a successful realloc counts as freeing the old block and allocating the new one
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+/// What was allocated while running a piece of code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// Highest amount of heap held at once, on top of what was already live before. This is
+    /// not the resident set size, which also counts the stack, the code and freed pages kept
+    pub heap_peak_bytes: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} heap peak",
+            self.allocations,
+            format_bytes(self.allocated_bytes),
+            format_bytes(self.heap_peak_bytes)
+        )
+    }
+}
+
+impl AllocStats {
+    /// Stats of two pieces of code run one after the other
+    pub fn then(self, next: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations + next.allocations,
+            allocated_bytes: self.allocated_bytes + next.allocated_bytes,
+            heap_peak_bytes: self.heap_peak_bytes.max(next.heap_peak_bytes),
+        }
+    }
+}
+
+/// Runs `f` and reports its allocations.
+///
+/// Only meaningful when [`CountingAllocator`] is the global allocator, all counters are zero
+/// otherwise. Measures may be nested, but allocations made by other threads in the meantime are
+/// counted as well.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+
+    let outer_peak = HEAP_PEAK_BYTES.swap(live_bytes, Ordering::Relaxed);
+
+    let res = f();
+
+    let peak = HEAP_PEAK_BYTES.fetch_max(outer_peak, Ordering::Relaxed);
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+        heap_peak_bytes: peak.saturating_sub(live_bytes),
+    };
+
+    (res, stats)
+}
+
+/// Highest resident set size of the process so far, `None` where the system does not tell
+pub fn peak_resident_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+
+    // `VmHWM:     1234 kB`
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    Some(kib * 1024)
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.5 KiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alloc::{format_bytes, measure, peak_resident_bytes, CountingAllocator};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let mut values: Vec<u64> = Vec::with_capacity(1024);
+            values.extend(0..1024);
+            values.len()
+        });
+
+        assert_eq!(len, 1024);
+
+        // other tests may allocate concurrently, so only lower bounds are reliable
+        assert!(stats.allocations >= 1);
+        assert!(stats.allocated_bytes >= 8 * 1024);
+        assert!(stats.heap_peak_bytes >= 8 * 1024);
+    }
+
+    #[test]
+    fn test_nested_measure() {
+        let ((_, inner), outer) = measure(|| {
+            let before = vec![0_u8; 64 * 1024];
+            drop(before);
+
+            measure(|| vec![0_u8; 1024].len())
+        });
+
+        // the smaller inner peak does not hide the one the outer measure saw before it
+        assert!(inner.heap_peak_bytes >= 1024);
+        assert!(outer.heap_peak_bytes >= 64 * 1024);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_peak_resident_bytes() {
+        assert!(peak_resident_bytes().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(12), "12 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,314 @@
+//! Sets of small integers stored as bits, either in a growable vector or in a fixed array.
+
+use std::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign},
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Backing words of a bitset
+pub trait Storage: Clone {
+    fn empty() -> Self;
+    fn words(&self) -> &[u64];
+    fn words_mut(&mut self) -> &mut [u64];
+    /// Makes room for at least `len` words
+    fn grow(&mut self, len: usize);
+}
+
+impl Storage for Vec<u64> {
+    fn empty() -> Self {
+        vec![]
+    }
+
+    fn words(&self) -> &[u64] {
+        self
+    }
+
+    fn words_mut(&mut self) -> &mut [u64] {
+        self
+    }
+
+    fn grow(&mut self, len: usize) {
+        if len > self.len() {
+            self.resize(len, 0);
+        }
+    }
+}
+
+impl<const N: usize> Storage for [u64; N] {
+    fn empty() -> Self {
+        [0; N]
+    }
+
+    fn words(&self) -> &[u64] {
+        self
+    }
+
+    fn words_mut(&mut self) -> &mut [u64] {
+        self
+    }
+
+    fn grow(&mut self, len: usize) {
+        assert!(len <= N, "bit out of the {} bits of the set", N * WORD_BITS);
+    }
+}
+
+/// Set of integers, growing as needed to hold the largest one
+#[derive(Clone, Default)]
+pub struct BitSet<S = Vec<u64>> {
+    words: S,
+}
+
+/// Set of integers below `64 * N`, without allocating
+pub type FixedBitSet<const N: usize> = BitSet<[u64; N]>;
+
+impl<S: Storage> BitSet<S> {
+    pub fn new() -> Self {
+        Self { words: S::empty() }
+    }
+
+    /// Empty set with room for the bits below `bits`
+    pub fn with_capacity(bits: usize) -> Self {
+        let mut words = S::empty();
+        words.grow(bits.div_ceil(WORD_BITS));
+
+        Self { words }
+    }
+
+    pub fn contains(&self, bit: usize) -> bool {
+        self.words
+            .words()
+            .get(bit / WORD_BITS)
+            .is_some_and(|word| word >> (bit % WORD_BITS) & 1 == 1)
+    }
+
+    /// Adds the bit, returning whether it was missing
+    pub fn insert(&mut self, bit: usize) -> bool {
+        self.words.grow(bit / WORD_BITS + 1);
+
+        let word = &mut self.words.words_mut()[bit / WORD_BITS];
+        let mask = 1 << (bit % WORD_BITS);
+        let missing = *word & mask == 0;
+
+        *word |= mask;
+
+        missing
+    }
+
+    /// Removes the bit, returning whether it was present
+    pub fn remove(&mut self, bit: usize) -> bool {
+        let Some(word) = self.words.words_mut().get_mut(bit / WORD_BITS) else {
+            return false;
+        };
+
+        let mask = 1 << (bit % WORD_BITS);
+        let present = *word & mask != 0;
+
+        *word &= !mask;
+
+        present
+    }
+
+    pub fn clear(&mut self) {
+        self.words.words_mut().fill(0);
+    }
+
+    /// Number of bits set
+    pub fn len(&self) -> usize {
+        self.words
+            .words()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.words().iter().all(|word| *word == 0)
+    }
+
+    /// Number of bits set strictly below `bit`
+    pub fn rank(&self, bit: usize) -> usize {
+        let words = self.words.words();
+        let (full, rest) = (bit / WORD_BITS, bit % WORD_BITS);
+
+        let below: usize = words
+            .iter()
+            .take(full)
+            .map(|word| word.count_ones() as usize)
+            .sum();
+
+        let partial = match words.get(full) {
+            Some(word) if rest > 0 => (word & ((1 << rest) - 1)).count_ones() as usize,
+            _ => 0,
+        };
+
+        below + partial
+    }
+
+    /// Bits set, in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .words()
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &word)| {
+                let mut word = word;
+
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+
+                    Some(index * WORD_BITS + bit)
+                })
+            })
+    }
+
+    /// Applies `op` to each pair of words, missing words counting as zeros
+    fn combine(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        let other = other.words.words();
+
+        self.words.grow(other.len());
+
+        for (i, word) in self.words.words_mut().iter_mut().enumerate() {
+            *word = op(*word, other.get(i).copied().unwrap_or(0));
+        }
+    }
+}
+
+impl<S: Storage> PartialEq for BitSet<S> {
+    /// Sets are equal when they hold the same bits, whatever the size of their storage
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (self.words.words(), other.words.words());
+
+        (0..a.len().max(b.len()))
+            .all(|i| a.get(i).copied().unwrap_or(0) == b.get(i).copied().unwrap_or(0))
+    }
+}
+
+impl<S: Storage> Eq for BitSet<S> {}
+
+impl<S: Storage> fmt::Debug for BitSet<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<S: Storage> FromIterator<usize> for BitSet<S> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<S: Storage> Extend<usize> for BitSet<S> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for bit in iter {
+            self.insert(bit);
+        }
+    }
+}
+
+macro_rules! impl_set_operation {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:expr) => {
+        impl<S: Storage> $assign_trait<&BitSet<S>> for BitSet<S> {
+            fn $assign_method(&mut self, other: &BitSet<S>) {
+                self.combine(other, $op);
+            }
+        }
+
+        impl<S: Storage> $trait for &BitSet<S> {
+            type Output = BitSet<S>;
+
+            fn $method(self, other: &BitSet<S>) -> BitSet<S> {
+                let mut res = self.clone();
+                res.combine(other, $op);
+                res
+            }
+        }
+    };
+}
+
+impl_set_operation!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+impl_set_operation!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+impl_set_operation!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+// the difference: bits of the first set missing from the second
+impl_set_operation!(Sub, sub, SubAssign, sub_assign, |a, b| a & !b);
+
+#[cfg(test)]
+mod tests {
+    use crate::bitset::{BitSet, FixedBitSet};
+
+    #[test]
+    fn test_membership() {
+        let mut set: BitSet = BitSet::new();
+
+        assert!(set.is_empty());
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(130));
+
+        assert!(set.contains(3) && set.contains(130));
+        assert!(!set.contains(4) && !set.contains(1000));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 130]);
+        assert_eq!(format!("{set:?}"), "{3, 130}");
+
+        assert!(set.remove(130));
+        assert!(!set.remove(130));
+        assert!(!set.remove(5000));
+        assert_eq!(set, [3].into_iter().collect());
+    }
+
+    #[test]
+    fn test_rank() {
+        let set: BitSet = [0, 5, 63, 64, 100].into_iter().collect();
+
+        assert_eq!(set.rank(0), 0);
+        assert_eq!(set.rank(1), 1);
+        assert_eq!(set.rank(63), 2);
+        assert_eq!(set.rank(64), 3);
+        assert_eq!(set.rank(65), 4);
+        assert_eq!(set.rank(1000), 5);
+    }
+
+    #[test]
+    fn test_operations() {
+        let a: BitSet = [1, 2, 3, 70].into_iter().collect();
+        let b: BitSet = [3, 4, 200].into_iter().collect();
+
+        assert_eq!((&a & &b).iter().collect::<Vec<_>>(), vec![3]);
+        assert_eq!(
+            (&a | &b).iter().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 70, 200]
+        );
+        assert_eq!((&a ^ &b).iter().collect::<Vec<_>>(), vec![1, 2, 4, 70, 200]);
+        assert_eq!((&a - &b).iter().collect::<Vec<_>>(), vec![1, 2, 70]);
+
+        let mut c = a.clone();
+        c -= &a;
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn test_fixed() {
+        let a: FixedBitSet<2> = [41, 48, 83, 86, 17].into_iter().collect();
+        let b: FixedBitSet<2> = [83, 86, 6, 31, 17, 9, 48, 53].into_iter().collect();
+
+        assert_eq!((&a & &b).len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the 64 bits")]
+    fn test_fixed_overflow() {
+        let mut set: FixedBitSet<1> = BitSet::new();
+        set.insert(64);
+    }
+}
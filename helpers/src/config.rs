@@ -0,0 +1,389 @@
+//! Settings of the workspace, read from `aoc.toml` at its root. Environment variables override
+//! the file, and the runner's command line flags override both.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::Deserialize;
+
+use crate::{leaderboard::SESSION_ENV, Day};
+
+/// Environment variable holding the path of the configuration file
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+/// Configuration file used when `AOC_CONFIG` is not set, optional
+pub const DEFAULT_CONFIG_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml");
+
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+pub const YEAR_ENV: &str = "AOC_YEAR";
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+pub const FORMAT_ENV: &str = "AOC_FORMAT";
+pub const TIMEOUT_ENV: &str = "AOC_TIMEOUT";
+pub const MEMORY_ENV: &str = "AOC_MEMORY";
+pub const REGRESSION_ENV: &str = "AOC_REGRESSION_THRESHOLD";
+
+/// How the runner prints results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {s}, expected text or json")),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// File holding the session cookie, relative to the workspace, used when `AOC_SESSION` is
+    /// not set
+    pub session_file: Option<PathBuf>,
+    /// Year of the days addressed without a year, the latest one if not set
+    pub year: Option<u16>,
+    /// Directory of the inputs of each day, relative to the workspace. `{crate}`, `{year}` and
+    /// `{day}` are replaced by the directory of the day crate, the year and the two digits day.
+    pub inputs: Option<String>,
+    pub format: Format,
+    /// Time budget of each part, in seconds
+    pub timeout: Option<f64>,
+    /// Memory budget of each part, in MiB, 0 to disable the limit
+    pub memory: Option<u64>,
+    /// Slowdown over the best time of a part on the machine, in percent, failing benchmarks
+    pub regression_threshold: Option<f64>,
+    /// Parameters of the solvers, by day id such as `2023/d11`
+    pub days: BTreeMap<String, toml::Table>,
+}
+
+impl Config {
+    /// Reads the configuration file, if any, then applies the environment variables
+    pub fn load() -> io::Result<Self> {
+        let path = env::var_os(CONFIG_ENV)
+            .map_or_else(|| PathBuf::from(DEFAULT_CONFIG_FILE), PathBuf::from);
+
+        let mut config = match fs::read_to_string(&path) {
+            Ok(content) => Self::from_toml(&content)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?,
+            // only a file explicitly asked for must exist
+            Err(e) if e.kind() == ErrorKind::NotFound && env::var_os(CONFIG_ENV).is_none() => {
+                Self::default()
+            }
+            Err(e) => {
+                return Err(io::Error::new(
+                    e.kind(),
+                    format!("cannot read {}: {e}", path.display()),
+                ))
+            }
+        };
+
+        config.apply_env(|name| env::var(name).ok())?;
+
+        Ok(config)
+    }
+
+    pub fn from_toml(content: &str) -> io::Result<Self> {
+        let config: Self = toml::from_str(content).map_err(invalid)?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Overrides the settings with the variables returned by `lookup`
+    fn apply_env(&mut self, lookup: impl Fn(&str) -> Option<String>) -> io::Result<()> {
+        fn parse<T: FromStr>(name: &str, value: String) -> io::Result<T>
+        where
+            T::Err: ToString,
+        {
+            value
+                .parse()
+                .map_err(|e: T::Err| invalid(format!("invalid {name}: {}", e.to_string())))
+        }
+
+        if let Some(value) = lookup(SESSION_FILE_ENV) {
+            self.session_file = Some(value.into());
+        }
+        if let Some(value) = lookup(YEAR_ENV) {
+            self.year = Some(parse(YEAR_ENV, value)?);
+        }
+        if let Some(value) = lookup(INPUTS_ENV) {
+            self.inputs = Some(value);
+        }
+        if let Some(value) = lookup(FORMAT_ENV) {
+            self.format = parse(FORMAT_ENV, value)?;
+        }
+        if let Some(value) = lookup(TIMEOUT_ENV) {
+            self.timeout = Some(parse(TIMEOUT_ENV, value)?);
+        }
+        if let Some(value) = lookup(MEMORY_ENV) {
+            self.memory = Some(parse(MEMORY_ENV, value)?);
+        }
+        if let Some(value) = lookup(REGRESSION_ENV) {
+            self.regression_threshold = Some(parse(REGRESSION_ENV, value)?);
+        }
+
+        self.validate()
+    }
+
+    fn validate(&self) -> io::Result<()> {
+        if let Some(year) = self.year {
+            if year < 2015 {
+                return Err(invalid(format!(
+                    "invalid year {year}, the first one is 2015"
+                )));
+            }
+        }
+
+        if let Some(timeout) = self.timeout {
+            if !(timeout > 0.0 && timeout.is_finite()) {
+                return Err(invalid(format!(
+                    "invalid timeout {timeout}, must be positive"
+                )));
+            }
+        }
+
+        if let Some(threshold) = self.regression_threshold {
+            if !(threshold >= 0.0 && threshold.is_finite()) {
+                return Err(invalid(format!(
+                    "invalid regression threshold {threshold}, must be a positive percentage"
+                )));
+            }
+        }
+
+        if let Some(inputs) = &self.inputs {
+            let unknown = inputs
+                .split('{')
+                .skip(1)
+                .filter_map(|s| s.split_once('}').map(|(name, _)| name))
+                .find(|name| !["crate", "year", "day"].contains(name));
+
+            if let Some(name) = unknown {
+                return Err(invalid(format!(
+                    "unknown placeholder {{{name}}} in inputs, expected {{crate}}, {{year}} or {{day}}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the parameters are given to existing days, and are ones they accept
+    pub fn check_days(&self, days: &[&Day]) -> io::Result<()> {
+        for id in self.days.keys() {
+            match days.iter().find(|d| d.id() == *id) {
+                Some(day) => self.check_params(day)?,
+                None => return Err(invalid(format!("unknown day {id} in days"))),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the parameters given to the day are ones it accepts
+    pub fn check_params(&self, day: &Day) -> io::Result<()> {
+        let Some(params) = self.days.get(&day.id()) else {
+            return Ok(());
+        };
+
+        match params
+            .keys()
+            .find(|name| !day.params.contains(&name.as_str()))
+        {
+            Some(name) if day.params.is_empty() => Err(invalid(format!(
+                "unknown parameter {name} of {}, which takes none",
+                day.id()
+            ))),
+            Some(name) => Err(invalid(format!(
+                "unknown parameter {name} of {}, expected one of {}",
+                day.id(),
+                day.params.join(", ")
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Parameters of the solvers of the day
+    pub fn params(&self, day: &Day) -> toml::Table {
+        self.days.get(&day.id()).cloned().unwrap_or_default()
+    }
+
+    pub fn inputs_dir(&self, day: &Day) -> PathBuf {
+        let Some(inputs) = &self.inputs else {
+            return PathBuf::from(day.inputs_dir);
+        };
+
+        let workspace = workspace();
+        let crate_dir = day.crate_dir();
+
+        let dir = inputs
+            .replace(
+                "{crate}",
+                &crate_dir
+                    .strip_prefix(workspace)
+                    .unwrap_or(crate_dir)
+                    .to_string_lossy(),
+            )
+            .replace("{year}", &day.year.to_string())
+            .replace("{day}", &format!("{:02}", day.day));
+
+        workspace.join(dir)
+    }
+
+    pub fn input_path(&self, day: &Day) -> PathBuf {
+        self.inputs_dir(day).join("input.txt")
+    }
+
+    /// Page of the puzzle, saved next to the inputs
+    pub fn puzzle_path(&self, day: &Day) -> PathBuf {
+        self.inputs_dir(day).join("puzzle.html")
+    }
+
+    /// Session cookie from `AOC_SESSION`, else from the session file
+    pub fn session(&self) -> io::Result<String> {
+        if let Ok(session) = env::var(SESSION_ENV) {
+            return Ok(session);
+        }
+
+        let path = self.session_file.as_ref().ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::NotFound,
+                format!("set {SESSION_ENV}, or session_file in aoc.toml"),
+            )
+        })?;
+
+        let path = workspace().join(path);
+
+        fs::read_to_string(&path)
+            .map(|session| session.trim().to_owned())
+            .map_err(|e| io::Error::new(e.kind(), format!("cannot read {}: {e}", path.display())))
+    }
+}
+
+/// Root of the workspace, the parent of this crate
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn invalid(error: impl ToString) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        config::{workspace, Config, Format},
+        Day,
+    };
+
+    const DAY: Day = Day {
+        year: 2023,
+        day: 11,
+        title: "Cosmic Expansion",
+        inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../d11/inputs"),
+        params: &["expansion"],
+        part_1: None,
+        part_2: None,
+    };
+
+    #[test]
+    fn test_parse() {
+        let config = Config::from_toml(
+            r#"
+            year = 2023
+            format = "json"
+            timeout = 2.5
+
+            [days."2023/d11"]
+            expansion = 10
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.memory, None);
+        assert_eq!(config.params(&DAY)["expansion"].as_integer(), Some(10));
+        assert!(config.check_days(&[&DAY]).is_ok());
+
+        assert_eq!(Config::from_toml("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_invalid() {
+        for content in [
+            "yaer = 2023",
+            "year = 2012",
+            "timeout = -1.0",
+            "regression_threshold = -5.0",
+            "format = \"yaml\"",
+            "inputs = \"{crate}/{name}\"",
+            "[session]",
+        ] {
+            assert!(Config::from_toml(content).is_err(), "{content}");
+        }
+
+        let config = Config::from_toml("[days.\"2023/d11\"]\nexpanson = 10").unwrap();
+        let error = config.check_days(&[&DAY]).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "unknown parameter expanson of 2023/d11, expected one of expansion"
+        );
+
+        let config = Config::from_toml("[days.\"2023/d13\"]\nexpansion = 10").unwrap();
+        assert!(config.check_days(&[&DAY]).is_err());
+    }
+
+    #[test]
+    fn test_env() {
+        let mut config = Config::from_toml("year = 2023\ntimeout = 5.0").unwrap();
+        let env = HashMap::from([("AOC_YEAR", "2024"), ("AOC_FORMAT", "json")]);
+
+        config
+            .apply_env(|name| env.get(name).map(|v| v.to_string()))
+            .unwrap();
+
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.timeout, Some(5.0));
+
+        assert!(config
+            .apply_env(|name| (name == "AOC_MEMORY").then(|| "lots".to_owned()))
+            .is_err());
+    }
+
+    #[test]
+    fn test_inputs_dir() {
+        // a day crate of the workspace, here helpers, is named relative to it
+        let day = Day {
+            inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+            ..DAY
+        };
+        let config = Config::from_toml("inputs = \"shared/{crate}\"").unwrap();
+        assert_eq!(config.inputs_dir(&day), workspace().join("shared/helpers"));
+
+        assert!(Config::default().inputs_dir(&DAY).ends_with("d11/inputs"));
+
+        let config = Config::from_toml("inputs = \"inputs/{year}/{day}\"").unwrap();
+        assert!(config.inputs_dir(&DAY).ends_with("inputs/2023/11"));
+
+        let config = Config::from_toml("inputs = \"{crate}/data\"").unwrap();
+        assert!(config.input_path(&DAY).ends_with("d11/data/input.txt"));
+    }
+}
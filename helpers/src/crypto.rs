@@ -0,0 +1,167 @@
+use std::{
+    env, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Nonce,
+};
+
+/// Environment variable holding the key itself, hex encoded
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+/// Environment variable holding the path of the key file
+pub const KEY_FILE_ENV: &str = "AOC_INPUT_KEY_FILE";
+/// Key file used when neither environment variable is set, kept out of git
+pub const DEFAULT_KEY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.aoc-key");
+
+/// Extension appended to the name of an encrypted file, `input.txt` becomes `input.txt.enc`
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// Identifies the format of encrypted files, so that a future format can be told apart
+const MAGIC: &[u8; 4] = b"AOC1";
+const NONCE_LEN: usize = 12;
+
+/// Symmetric key protecting the inputs at rest
+pub struct InputKey(chacha20poly1305::Key);
+
+impl InputKey {
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    /// Parses a hex encoded 32 bytes key, surrounding whitespace is ignored
+    pub fn from_hex(hex: &str) -> io::Result<Self> {
+        let bytes =
+            hex::decode(hex.trim()).map_err(|e| invalid_data(format!("invalid key: {e}")))?;
+
+        if bytes.len() != 32 {
+            return Err(invalid_data(format!(
+                "invalid key: expected 32 bytes, got {}",
+                bytes.len()
+            )));
+        }
+
+        Ok(Self(*chacha20poly1305::Key::from_slice(&bytes)))
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    /// Loads the key from `AOC_INPUT_KEY`, else from the file at `AOC_INPUT_KEY_FILE`, else
+    /// from the default key file
+    pub fn load() -> io::Result<Self> {
+        if let Ok(hex) = env::var(KEY_ENV) {
+            return Self::from_hex(&hex);
+        }
+
+        let path = key_file();
+
+        let hex = fs::read_to_string(&path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!(
+                    "cannot read input key from {}: {e}, set {KEY_ENV} or {KEY_FILE_ENV}",
+                    path.display()
+                ),
+            )
+        })?;
+
+        Self::from_hex(&hex)
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let ciphertext = ChaCha20Poly1305::new(&self.0)
+            .encrypt(&nonce, plaintext)
+            .expect("encryption cannot fail for in-memory buffers");
+
+        [MAGIC.as_slice(), nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> io::Result<Vec<u8>> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| invalid_data("not an encrypted input".to_owned()))?;
+
+        if data.len() < NONCE_LEN {
+            return Err(invalid_data("truncated encrypted input".to_owned()));
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+        ChaCha20Poly1305::new(&self.0)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| invalid_data("cannot decrypt input, wrong key?".to_owned()))
+    }
+}
+
+/// Path of the key file, following `AOC_INPUT_KEY_FILE` when set
+pub fn key_file() -> PathBuf {
+    env::var_os(KEY_FILE_ENV).map_or_else(|| PathBuf::from(DEFAULT_KEY_FILE), PathBuf::from)
+}
+
+/// Path of the encrypted counterpart of a plaintext file
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(ENCRYPTED_EXTENSION);
+
+    PathBuf::from(name)
+}
+
+/// Encrypts `path` into its encrypted counterpart, returning the path written
+pub fn encrypt_file(key: &InputKey, path: &Path) -> io::Result<PathBuf> {
+    let encrypted = encrypted_path(path);
+
+    fs::write(&encrypted, key.encrypt(&fs::read(path)?))?;
+
+    Ok(encrypted)
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::crypto::{encrypted_path, InputKey};
+
+    #[test]
+    fn test_round_trip() {
+        let key = InputKey::generate();
+        let encrypted = key.encrypt(b"0 3 6 9 12 15\n");
+
+        assert_ne!(&encrypted[4..], b"0 3 6 9 12 15\n");
+        assert_eq!(key.decrypt(&encrypted).unwrap(), b"0 3 6 9 12 15\n");
+
+        // a different key cannot decrypt, and tampering is detected
+        assert!(InputKey::generate().decrypt(&encrypted).is_err());
+
+        let mut tampered = encrypted.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(key.decrypt(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_hex() {
+        let key = InputKey::generate();
+
+        assert_eq!(InputKey::from_hex(&key.to_hex()).unwrap().0, key.0);
+        assert!(InputKey::from_hex("abcd").is_err());
+        assert!(InputKey::from_hex("not hex").is_err());
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(
+            encrypted_path(Path::new("d08/inputs/input.txt")),
+            Path::new("d08/inputs/input.txt.enc")
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    cell::RefCell,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{config::Config, read_input, trace};
+
+/// Solves one part of a puzzle from its raw input and formats the answer
+pub type Solver = fn(&str) -> String;
+
+/// Describes a puzzle day and the solvers implemented for each of its parts
+#[derive(Debug)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Directory holding the inputs, usually `concat!(env!("CARGO_MANIFEST_DIR"), "/inputs")`
+    pub inputs_dir: &'static str,
+    /// Names of the parameters the solvers read with [`param`], set in the `days` table of
+    /// `aoc.toml`
+    pub params: &'static [&'static str],
+    pub part_1: Option<Solver>,
+    pub part_2: Option<Solver>,
+}
+
+impl Day {
+    /// Name of the day within its year, e.g. `d08`
+    pub fn name(&self) -> String {
+        format!("d{:02}", self.day)
+    }
+
+    /// Identifies the day across years, e.g. `2023/d08`
+    pub fn id(&self) -> String {
+        format!("{}/{}", self.year, self.name())
+    }
+
+    /// Directory of the crate holding the day
+    pub fn crate_dir(&self) -> &Path {
+        Path::new(self.inputs_dir)
+            .parent()
+            .unwrap_or(Path::new("."))
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://adventofcode.com/{}/day/{}", self.year, self.day)
+    }
+
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+
+    /// Runs the solver of the part in a span named after it, with the parameters of the day set
+    /// in the configuration, `None` if it is not implemented
+    pub fn solve(&self, part: u8, input: &str, config: &Config) -> Option<String> {
+        let solver = self.part(part)?;
+
+        let span = match part {
+            1 => tracing::info_span!("part1", day = %self.id()),
+            _ => tracing::info_span!("part2", day = %self.id()),
+        };
+
+        PARAMS.with(|params| *params.borrow_mut() = config.params(self));
+        let answer = span.in_scope(|| solver(input));
+        PARAMS.with(|params| params.borrow_mut().clear());
+
+        Some(answer)
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        Path::new(self.inputs_dir).join("input.txt")
+    }
+
+    /// Reads the input, decrypting it if it is only stored encrypted
+    pub fn load_input(&self) -> io::Result<String> {
+        read_input(&self.input_path())
+    }
+}
+
+thread_local! {
+    /// Parameters of the day being solved on this thread
+    static PARAMS: RefCell<toml::Table> = RefCell::default();
+}
+
+/// Parameter of the day being solved, `default` when the configuration does not set it.
+///
+/// Panics if the parameter has the wrong type, as the configuration cannot be fixed from there.
+pub fn param<T: DeserializeOwned>(name: &str, default: T) -> T {
+    PARAMS.with(|params| match params.borrow().get(name) {
+        Some(value) => value
+            .clone()
+            .try_into()
+            .unwrap_or_else(|e| panic!("invalid parameter {name}: {e}")),
+        None => default,
+    })
+}
+
+/// Reads the accepted answers stored next to an input: those of `inputs/input.txt` are in
+/// `inputs/input.answers`, one line per part, left empty when unknown
+pub fn known_answers(input: &Path) -> io::Result<[Option<String>; 2]> {
+    match fs::read_to_string(input.with_extension("answers")) {
+        Ok(content) => Ok(parse_answers(&content)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok([None, None]),
+        Err(e) => Err(e),
+    }
+}
+
+fn parse_answers(content: &str) -> [Option<String>; 2] {
+    let mut answers = content.lines().map(|line| {
+        Some(line.trim())
+            .filter(|l| !l.is_empty())
+            .map(str::to_owned)
+    });
+
+    [answers.next().flatten(), answers.next().flatten()]
+}
+
+/// Runs every implemented part of the day on its input and prints the answers.
+///
+/// Pass `-v` for the timings of each span, see [`trace::init`] for the other options.
+pub fn run(day: &Day) {
+    let _guard = trace::init(trace::verbosity_from_args(), &day.id())
+        .unwrap_or_else(|e| panic!("cannot set up tracing: {e}"));
+
+    let config = Config::load()
+        .and_then(|config| config.check_params(day).map(|_| config))
+        .unwrap_or_else(|e| panic!("invalid configuration: {e}"));
+
+    let path = config.input_path(day);
+    let input = read_input(&path).unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()));
+
+    for part in 1..=2 {
+        if let Some(answer) = day.solve(part, &input, &config) {
+            println!("part{part} = {answer}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::Config,
+        day::{param, parse_answers},
+        Day,
+    };
+
+    #[test]
+    fn test_param() {
+        const DAY: Day = Day {
+            year: 2023,
+            day: 11,
+            title: "Cosmic Expansion",
+            inputs_dir: "d11/inputs",
+            params: &["expansion"],
+            part_1: Some(|_| param("expansion", 2usize).to_string()),
+            part_2: None,
+        };
+
+        let config = Config::from_toml("[days.\"2023/d11\"]\nexpansion = 10").unwrap();
+
+        assert_eq!(DAY.solve(1, "", &config), Some("10".to_owned()));
+        assert_eq!(DAY.solve(1, "", &Config::default()), Some("2".to_owned()));
+        assert_eq!(DAY.solve(2, "", &config), None);
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("12643\n13133452426987\n"),
+            [Some("12643".to_owned()), Some("13133452426987".to_owned())]
+        );
+        assert_eq!(parse_answers("\n995"), [None, Some("995".to_owned())]);
+        assert_eq!(parse_answers("7939"), [Some("7939".to_owned()), None]);
+    }
+}
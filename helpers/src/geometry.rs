@@ -0,0 +1,181 @@
+//! Simple polygons on the integer lattice, given by their vertices in order, either clockwise or
+//! counter-clockwise. The last vertex connects back to the first one.
+
+use crate::math::gcd;
+
+pub type Point = (i64, i64);
+
+/// Edges of the polygon, as pairs of consecutive vertices
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/// Twice the signed area, with the shoelace formula: positive when the vertices go
+/// counter-clockwise with the y axis pointing up. Twice the area is always an integer.
+pub fn double_signed_area(vertices: &[Point]) -> i64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum()
+}
+
+/// Twice the area of the polygon
+pub fn double_area(vertices: &[Point]) -> u64 {
+    double_signed_area(vertices).unsigned_abs()
+}
+
+/// Number of lattice points on the edges of the polygon
+pub fn boundary_points(vertices: &[Point]) -> u64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| gcd(x1.abs_diff(x2), y1.abs_diff(y2)))
+        .sum()
+}
+
+/// Number of lattice points strictly inside the polygon, with Pick's theorem. `None` for the
+/// degenerate polygons, with fewer than 3 vertices or no area, such as a line going back and
+/// forth, which the theorem does not apply to.
+pub fn interior_points(vertices: &[Point]) -> Option<u64> {
+    let double_area = double_area(vertices);
+
+    if vertices.len() < 3 || double_area == 0 {
+        return None;
+    }
+
+    // A = I + B / 2 - 1
+    (double_area + 2)
+        .checked_sub(boundary_points(vertices))
+        .map(|double_interior| double_interior / 2)
+}
+
+/// Length of the boundary of a rectilinear polygon, whose edges are all horizontal or vertical
+pub fn rectilinear_perimeter(vertices: &[Point]) -> u64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| {
+            debug_assert!(
+                x1 == x2 || y1 == y2,
+                "edge ({x1}, {y1}) - ({x2}, {y2}) is neither horizontal nor vertical"
+            );
+
+            x1.abs_diff(x2) + y1.abs_diff(y2)
+        })
+        .sum()
+}
+
+/// Whether the point lies on an edge of the polygon
+pub fn on_boundary(vertices: &[Point], (x, y): Point) -> bool {
+    edges(vertices).any(|((x1, y1), (x2, y2))| {
+        let cross = (x2 - x1) * (y - y1) - (y2 - y1) * (x - x1);
+
+        cross == 0 && x1.min(x2) <= x && x <= x1.max(x2) && y1.min(y2) <= y && y <= y1.max(y2)
+    })
+}
+
+/// Number of times the polygon winds around the point, counter-clockwise turns counting as
+/// positive. Meaningless for points on the boundary.
+pub fn winding_number(vertices: &[Point], (x, y): Point) -> i64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| {
+            // sign of the position of the point relative to the edge
+            let side = (x2 - x1) * (y - y1) - (y2 - y1) * (x - x1);
+
+            if y1 <= y && y < y2 && side > 0 {
+                1
+            } else if y2 <= y && y < y1 && side < 0 {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Whether the point lies strictly inside the polygon
+pub fn contains(vertices: &[Point], point: Point) -> bool {
+    !on_boundary(vertices, point) && winding_number(vertices, point) != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{
+        boundary_points, contains, double_area, double_signed_area, interior_points, on_boundary,
+        rectilinear_perimeter, winding_number,
+    };
+
+    const SQUARE: [(i64, i64); 4] = [(0, 0), (4, 0), (4, 4), (0, 4)];
+
+    #[test]
+    fn test_area() {
+        assert_eq!(double_signed_area(&SQUARE), 32);
+
+        let mut clockwise = SQUARE;
+        clockwise.reverse();
+        assert_eq!(double_signed_area(&clockwise), -32);
+        assert_eq!(double_area(&clockwise), 32);
+
+        assert_eq!(double_area(&[(0, 0), (3, 0), (0, 1)]), 3);
+    }
+
+    #[test]
+    fn test_pick() {
+        assert_eq!(boundary_points(&SQUARE), 16);
+        assert_eq!(interior_points(&SQUARE), Some(9));
+
+        // a triangle with a diagonal edge
+        let triangle = [(0, 0), (4, 0), (0, 4)];
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), Some(3));
+
+        assert_eq!(rectilinear_perimeter(&SQUARE), 16);
+    }
+
+    #[test]
+    fn test_pick_degenerate() {
+        assert_eq!(interior_points(&[]), None);
+        assert_eq!(interior_points(&[(1, 1)]), None);
+
+        // segments travelled both ways, whatever their length
+        assert_eq!(interior_points(&[(0, 0), (1, 0)]), None);
+        assert_eq!(interior_points(&[(0, 0), (4, 0)]), None);
+        assert_eq!(interior_points(&[(0, 0), (2, 0), (4, 0)]), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "neither horizontal nor vertical")]
+    fn test_rectilinear_perimeter_diagonal() {
+        rectilinear_perimeter(&[(0, 0), (4, 0), (0, 4)]);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        // a U shape, concave at the top
+        let u = [
+            (0, 0),
+            (6, 0),
+            (6, 6),
+            (4, 6),
+            (4, 2),
+            (2, 2),
+            (2, 6),
+            (0, 6),
+        ];
+
+        assert!(contains(&u, (1, 1)));
+        assert!(contains(&u, (1, 5)));
+        assert!(contains(&u, (5, 5)));
+        assert!(!contains(&u, (3, 4)));
+        assert!(!contains(&u, (7, 1)));
+
+        assert!(on_boundary(&u, (3, 2)));
+        assert!(!contains(&u, (3, 2)));
+
+        assert_eq!(winding_number(&u, (1, 1)), 1);
+
+        let mut clockwise = u;
+        clockwise.reverse();
+        assert_eq!(winding_number(&clockwise, (1, 1)), -1);
+        assert_eq!(winding_number(&clockwise, (3, 4)), 0);
+    }
+}
@@ -0,0 +1,594 @@
+//! Graph traversals over implicit graphs, described by a start node and a function returning the
+//! neighbours of a node. Nodes are usually grid positions or node names.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Breadth-first traversal, yielding every reachable node with its distance to the start in edges
+pub struct Bfs<N, F> {
+    queue: VecDeque<(N, usize)>,
+    seen: HashSet<N>,
+    neighbours: F,
+}
+
+impl<N, F, I> Iterator for Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = (N, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, distance) = self.queue.pop_front()?;
+
+        for next in (self.neighbours)(&node) {
+            if self.seen.insert(next.clone()) {
+                self.queue.push_back((next, distance + 1));
+            }
+        }
+
+        Some((node, distance))
+    }
+}
+
+pub fn bfs<N, F, I>(start: N, neighbours: F) -> Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    Bfs {
+        queue: VecDeque::from([(start.clone(), 0)]),
+        seen: HashSet::from([start]),
+        neighbours,
+    }
+}
+
+/// Depth-first traversal, yielding every reachable node in preorder
+pub struct Dfs<N, F> {
+    stack: Vec<N>,
+    seen: HashSet<N>,
+    neighbours: F,
+}
+
+impl<N, F, I> Iterator for Dfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = loop {
+            let node = self.stack.pop()?;
+
+            if self.seen.insert(node.clone()) {
+                break node;
+            }
+        };
+
+        let mut neighbours: Vec<N> = (self.neighbours)(&node)
+            .into_iter()
+            .filter(|n| !self.seen.contains(n))
+            .collect();
+
+        // visit the neighbours in the order they were given
+        neighbours.reverse();
+        self.stack.extend(neighbours);
+
+        Some(node)
+    }
+}
+
+pub fn dfs<N, F, I>(start: N, neighbours: F) -> Dfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    Dfs {
+        stack: vec![start],
+        seen: HashSet::new(),
+        neighbours,
+    }
+}
+
+/// Shortest paths from a start node to every reachable node
+#[derive(Debug)]
+pub struct Paths<N, C> {
+    /// Cost of the best path to each node, and the node before it on that path
+    best: HashMap<N, (C, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.best.get(node).map(|(cost, _)| *cost)
+    }
+
+    /// Nodes of the shortest path from the start to `node`, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut current = node;
+
+        while let Some(previous) = &self.best.get(current)?.1 {
+            path.push(previous.clone());
+            current = previous;
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every reachable node with the cost of its shortest path
+    pub fn costs(&self) -> impl Iterator<Item = (&N, C)> {
+        self.best.iter().map(|(node, (cost, _))| (node, *cost))
+    }
+}
+
+/// Breadth-first search recording the parent of each node, to rebuild the shortest paths
+pub fn bfs_paths<N, F, I>(start: N, mut neighbours: F) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut best = HashMap::from([(start.clone(), (0, None))]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = best.entry(next.clone()) {
+                entry.insert((distance + 1, Some(node.clone())));
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    Paths { best }
+}
+
+/// Entry of the priority queue of Dijkstra and A*, ordered by priority only so that nodes do
+/// not need to be comparable
+struct Candidate<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed, as the binary heap pops the greatest element first
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's shortest paths from `start`, where `neighbours` returns each neighbour with the
+/// non-negative cost of the edge leading to it
+pub fn dijkstra<N, C, F, I>(start: N, mut neighbours: F) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut best = HashMap::new();
+
+    search(
+        start,
+        &mut neighbours,
+        |_| C::default(),
+        |_| false,
+        &mut best,
+    );
+
+    Paths { best }
+}
+
+/// A* search of the cheapest path from `start` to a node satisfying `is_goal`.
+///
+/// `heuristic` must never overestimate the remaining cost, for the path found to be optimal.
+/// Returns the path, both ends included, and its cost.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut best = HashMap::new();
+
+    let goal = search(start, &mut neighbours, heuristic, is_goal, &mut best)?;
+    let paths = Paths { best };
+
+    Some((paths.path_to(&goal)?, paths.cost(&goal)?))
+}
+
+/// Shared best-first search: explores until a goal is settled, returning it, or until every
+/// reachable node is settled
+fn search<N, C, F, I, H, G>(
+    start: N,
+    neighbours: &mut F,
+    mut heuristic: H,
+    mut is_goal: G,
+    best: &mut HashMap<N, (C, Option<N>)>,
+) -> Option<N>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut queue = BinaryHeap::new();
+
+    best.insert(start.clone(), (C::default(), None));
+    queue.push(Candidate {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+
+    while let Some(Candidate { cost, node, .. }) = queue.pop() {
+        // skip the stale entries of nodes reached again with a better cost
+        if best[&node].0 < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some(node);
+        }
+
+        for (next, edge_cost) in neighbours(&node) {
+            let next_cost = cost + edge_cost;
+
+            let improved = match best.get(&next) {
+                Some((known, _)) => next_cost < *known,
+                None => true,
+            };
+
+            if improved {
+                best.insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push(Candidate {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Orders the nodes so that each one comes before its successors, `None` if there is a cycle.
+///
+/// Successors missing from `nodes` are added to the ordering.
+pub fn topological_sort<N, F, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: F,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut order: Vec<N> = vec![];
+    let mut edges: HashMap<N, Vec<N>> = HashMap::new();
+    let mut in_degrees: HashMap<N, usize> = HashMap::new();
+
+    let mut pending: VecDeque<N> = nodes.into_iter().collect();
+
+    // discover every node and its edges first
+    while let Some(node) = pending.pop_front() {
+        if edges.contains_key(&node) {
+            continue;
+        }
+
+        let next: Vec<N> = successors(&node).into_iter().collect();
+
+        in_degrees.entry(node.clone()).or_insert(0);
+
+        for n in &next {
+            *in_degrees.entry(n.clone()).or_insert(0) += 1;
+            pending.push_back(n.clone());
+        }
+
+        order.push(node.clone());
+        edges.insert(node, next);
+    }
+
+    // then apply Kahn's algorithm, following the discovery order for stable results
+    let mut queue: VecDeque<N> = order
+        .drain(..)
+        .filter(|node| in_degrees[node] == 0)
+        .collect();
+
+    while let Some(node) = queue.pop_front() {
+        for next in &edges[&node] {
+            let in_degree = in_degrees.get_mut(next).unwrap();
+            *in_degree -= 1;
+
+            if *in_degree == 0 {
+                queue.push_back(next.clone());
+            }
+        }
+
+        order.push(node);
+    }
+
+    (order.len() == edges.len()).then_some(order)
+}
+
+/// Strongly connected components of the graph, each listed once, in reverse topological order
+/// of the condensed graph. Uses an iterative Tarjan's algorithm so deep graphs cannot overflow
+/// the stack.
+pub fn strongly_connected_components<N, F, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: F,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    // discovery index and lowest index reachable, for each visited node
+    let mut indices: HashMap<N, (usize, usize)> = HashMap::new();
+    let mut stack: Vec<N> = vec![];
+    let mut on_stack: HashSet<N> = HashSet::new();
+    let mut components = vec![];
+
+    for root in nodes {
+        if indices.contains_key(&root) {
+            continue;
+        }
+
+        let mut calls: Vec<(N, I::IntoIter)> = vec![];
+        let mut unvisited = Some(root);
+
+        loop {
+            if let Some(node) = unvisited.take() {
+                let index = indices.len();
+                indices.insert(node.clone(), (index, index));
+                stack.push(node.clone());
+                on_stack.insert(node.clone());
+                calls.push((node.clone(), successors(&node).into_iter()));
+            }
+
+            let Some((node, next)) = calls.last_mut() else {
+                break;
+            };
+
+            if let Some(next) = next.next() {
+                match indices.get(&next) {
+                    None => unvisited = Some(next),
+                    Some(&(next_index, _)) => {
+                        if on_stack.contains(&next) {
+                            let low = &mut indices.get_mut(node).unwrap().1;
+                            *low = (*low).min(next_index);
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            let node = node.clone();
+            calls.pop();
+
+            let (index, low) = indices[&node];
+
+            if let Some((parent, _)) = calls.last() {
+                let parent_low = &mut indices.get_mut(parent).unwrap().1;
+                *parent_low = (*parent_low).min(low);
+            }
+
+            if index == low {
+                let mut component = vec![];
+
+                while let Some(member) = stack.pop() {
+                    on_stack.remove(&member);
+                    let done = member == node;
+                    component.push(member);
+
+                    if done {
+                        break;
+                    }
+                }
+
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::graph::{
+        astar, bfs, bfs_paths, dfs, dijkstra, strongly_connected_components, topological_sort,
+    };
+
+    /// Open cells of a small maze, `#` being walls
+    fn grid_neighbours<'a>(
+        grid: &'a [&str],
+    ) -> impl Fn(&(usize, usize)) -> Vec<(usize, usize)> + 'a {
+        move |&(row, column)| {
+            let mut next = vec![];
+
+            if row > 0 {
+                next.push((row - 1, column));
+            }
+            if column > 0 {
+                next.push((row, column - 1));
+            }
+            next.push((row + 1, column));
+            next.push((row, column + 1));
+
+            next.into_iter()
+                .filter(|&(r, c)| grid.get(r).and_then(|l| l.as_bytes().get(c)) == Some(&b'.'))
+                .collect()
+        }
+    }
+
+    const MAZE: [&str; 4] = ["..#.", ".##.", "....", "#..."];
+
+    #[test]
+    fn test_bfs() {
+        let distances: HashMap<_, _> = bfs((0, 0), grid_neighbours(&MAZE)).collect();
+
+        assert_eq!(distances.len(), 12);
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(2, 1)], 3);
+        assert_eq!(distances[&(0, 3)], 7);
+        assert!(!distances.contains_key(&(0, 2)));
+
+        let paths = bfs_paths((0, 0), grid_neighbours(&MAZE));
+
+        assert_eq!(paths.cost(&(0, 3)), Some(7));
+        assert_eq!(
+            paths.path_to(&(0, 3)).unwrap(),
+            vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (2, 3),
+                (1, 3),
+                (0, 3)
+            ]
+        );
+        assert_eq!(paths.path_to(&(0, 2)), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let graph = HashMap::from([("a", vec!["b", "c"]), ("b", vec!["d"]), ("c", vec!["d"])]);
+        let successors = |n: &&str| graph.get(n).cloned().unwrap_or_default();
+
+        assert_eq!(
+            dfs("a", successors).collect::<Vec<_>>(),
+            vec!["a", "b", "d", "c"]
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = HashMap::from([
+            ("a", vec![("b", 7), ("c", 9), ("f", 14)]),
+            ("b", vec![("c", 10), ("d", 15)]),
+            ("c", vec![("d", 11), ("f", 2)]),
+            ("d", vec![("e", 6)]),
+            ("f", vec![("e", 9)]),
+        ]);
+        let neighbours = |n: &&str| graph.get(n).cloned().unwrap_or_default();
+
+        let paths = dijkstra("a", neighbours);
+
+        assert_eq!(paths.cost(&"e"), Some(20));
+        assert_eq!(paths.cost(&"d"), Some(20));
+        assert_eq!(paths.path_to(&"e").unwrap(), vec!["a", "c", "f", "e"]);
+        assert_eq!(paths.costs().count(), 6);
+    }
+
+    #[test]
+    fn test_astar() {
+        let neighbours = |node: &(usize, usize)| {
+            grid_neighbours(&MAZE)(node)
+                .into_iter()
+                .map(|n| (n, 1_usize))
+                .collect::<Vec<_>>()
+        };
+        let manhattan = |&(row, column): &(usize, usize)| row.abs_diff(0) + column.abs_diff(3);
+
+        let (path, cost) = astar((0, 0), neighbours, manhattan, |n| *n == (0, 3)).unwrap();
+
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[7]), ((0, 0), (0, 3)));
+
+        assert_eq!(astar((0, 0), neighbours, |_| 0, |n| *n == (0, 2)), None);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = HashMap::from([
+            ("shirt", vec!["tie", "belt"]),
+            ("tie", vec!["jacket"]),
+            ("trousers", vec!["shoes", "belt"]),
+            ("belt", vec!["jacket"]),
+        ]);
+        let successors = |n: &&str| graph.get(n).cloned().unwrap_or_default();
+
+        let order = topological_sort(["shirt", "trousers"], successors).unwrap();
+        let position = |n| order.iter().position(|o| *o == n).unwrap();
+
+        assert_eq!(order.len(), 6);
+        assert!(position("shirt") < position("tie"));
+        assert!(position("tie") < position("jacket"));
+        assert!(position("belt") < position("jacket"));
+        assert!(position("trousers") < position("shoes"));
+
+        let cycle = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![1])]);
+        assert_eq!(topological_sort([1], |n| cycle[n].clone()), None);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = HashMap::from([
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![1, 4]),
+            (4, vec![5]),
+            (5, vec![4]),
+            (6, vec![5]),
+        ]);
+
+        let mut components: Vec<Vec<i32>> =
+            strongly_connected_components(1..=6, |n| graph[n].clone())
+                .into_iter()
+                .map(|mut c| {
+                    c.sort();
+                    c
+                })
+                .collect();
+
+        // sinks come first
+        assert_eq!(components[0], vec![4, 5]);
+
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2, 3], vec![4, 5], vec![6]]);
+    }
+}
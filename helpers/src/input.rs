@@ -0,0 +1,72 @@
+use std::{
+    fs,
+    io::{self, Cursor, ErrorKind},
+    path::Path,
+};
+
+use crate::{
+    crypto::{encrypted_path, InputKey},
+    lines::{read_lines, Lines},
+};
+
+/// Reads an input, decrypting its encrypted counterpart when the plaintext file is absent.
+///
+/// The path `-` reads the standard input, gzip files are decompressed, and `\r\n` line endings
+/// are turned into `\n`. Invalid UTF-8 is reported with its line number.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    let _span = tracing::info_span!("read", path = %path.display()).entered();
+
+    match read_lines(path) {
+        Err(e) if e.kind() == ErrorKind::NotFound => read_encrypted(path, e),
+        lines => join(lines?),
+    }
+}
+
+/// Reads the encrypted counterpart of a missing plaintext input, reporting the plaintext one as
+/// missing when there is none, since it is the one callers asked for
+fn read_encrypted(path: &Path, not_found: io::Error) -> io::Result<String> {
+    let Ok(encrypted) = fs::read(encrypted_path(path)) else {
+        return Err(not_found);
+    };
+
+    tracing::debug!("decrypting {}", encrypted_path(path).display());
+
+    let plaintext = InputKey::load()?.decrypt(&encrypted)?;
+
+    join(Lines::new(Box::new(Cursor::new(plaintext)))?)
+}
+
+/// Lines each ending with `\n`
+fn join(lines: Lines) -> io::Result<String> {
+    let mut content = String::new();
+
+    for line in lines {
+        content.push_str(&line?);
+        content.push('\n');
+    }
+
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use crate::input::read_input;
+
+    #[test]
+    fn test_read_input() {
+        let dir = env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("input.txt");
+        fs::write(&path, b"one\r\ntwo").unwrap();
+        assert_eq!(read_input(&path).unwrap(), "one\ntwo\n");
+
+        fs::write(&path, b"one\ntw\xffo\n").unwrap();
+        let error = read_input(&path).unwrap_err();
+        assert!(error.to_string().starts_with("line 2: invalid utf-8"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
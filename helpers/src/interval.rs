@@ -0,0 +1,272 @@
+//! Arithmetic on half-open ranges of integers, to work on whole ranges of values instead of
+//! iterating over each value.
+
+use std::{
+    cmp::{max, min},
+    fmt,
+    ops::{Add, Sub},
+};
+
+/// Values from `start` included to `end` excluded, empty when `end <= start`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Range<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Range<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+impl<T: Copy + Ord> Range<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Values in both ranges, `None` if they do not overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(max(self.start, other.start), min(self.end, other.end));
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Values of this range that are not in `other`, in up to two pieces
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(intersection) = self.intersection(other) else {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        };
+
+        [
+            Self::new(self.start, intersection.start),
+            Self::new(intersection.end, self.end),
+        ]
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .collect()
+    }
+
+    /// Values in either range, `None` when they are neither overlapping nor adjacent as the union
+    /// would not be a single range
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(*other);
+        }
+        if other.is_empty() {
+            return Some(*self);
+        }
+
+        (self.start <= other.end && other.start <= self.end)
+            .then(|| Self::new(min(self.start, other.start), max(self.end, other.end)))
+    }
+
+    /// Cuts the range at every boundary falling strictly inside it
+    pub fn split(&self, boundaries: impl IntoIterator<Item = T>) -> Vec<Self> {
+        let mut boundaries: Vec<T> = boundaries
+            .into_iter()
+            .filter(|b| self.start < *b && *b < self.end)
+            .collect();
+
+        boundaries.sort();
+        boundaries.dedup();
+
+        let mut pieces = vec![];
+        let mut start = self.start;
+
+        for boundary in boundaries {
+            pieces.push(Self::new(start, boundary));
+            start = boundary;
+        }
+
+        if start < self.end {
+            pieces.push(Self::new(start, self.end));
+        }
+
+        pieces
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T>> Range<T> {
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Default> Range<T> {
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+/// Merges overlapping and adjacent ranges, returning disjoint ranges sorted by start
+pub fn union_all<T: Copy + Ord>(ranges: impl IntoIterator<Item = Range<T>>) -> Vec<Range<T>> {
+    let mut ranges: Vec<Range<T>> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+    ranges.sort();
+
+    let mut merged: Vec<Range<T>> = vec![];
+
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = max(last.end, range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// Piecewise translation of values: values of each source range are shifted to the destination
+/// range of the same length, other values are left as is
+#[derive(Debug, Clone, Default)]
+pub struct RangeMap<T> {
+    /// Source ranges with the start of their destination, sorted by source
+    entries: Vec<(Range<T>, T)>,
+}
+
+impl<T> RangeMap<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Maps `source` to the range of the same length starting at `destination`. Source ranges
+    /// must not overlap.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        let index = self.entries.partition_point(|(s, _)| *s < source);
+
+        self.entries.insert(index, (source, destination));
+    }
+
+    pub fn get(&self, value: T) -> T {
+        self.entries
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(source, destination)| {
+                value - source.start + *destination
+            })
+    }
+
+    /// Images of the values of `range`, one range per piece mapped differently, in order of the
+    /// source values
+    pub fn map_range(&self, range: Range<T>) -> Vec<Range<T>> {
+        let mut pieces = vec![];
+        let mut cursor = range.start;
+
+        for (source, destination) in &self.entries {
+            let Some(intersection) = source.intersection(&range) else {
+                continue;
+            };
+
+            if cursor < intersection.start {
+                pieces.push(Range::new(cursor, intersection.start));
+            }
+
+            pieces.push(Range::new(
+                intersection.start - source.start + *destination,
+                intersection.end - source.start + *destination,
+            ));
+
+            cursor = intersection.end;
+        }
+
+        if cursor < range.end {
+            pieces.push(Range::new(cursor, range.end));
+        }
+
+        pieces
+    }
+
+    /// Images of all the ranges, merged
+    pub fn map_ranges(&self, ranges: impl IntoIterator<Item = Range<T>>) -> Vec<Range<T>> {
+        union_all(ranges.into_iter().flat_map(|r| self.map_range(r)))
+    }
+}
+
+impl<T> FromIterator<(Range<T>, T)> for RangeMap<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        for (source, destination) in iter {
+            map.insert(source, destination);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::{union_all, Range, RangeMap};
+
+    fn r(start: u64, end: u64) -> Range<u64> {
+        Range::new(start, end)
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(Range::with_len(79, 14), r(79, 93));
+        assert_eq!(r(79, 93).len(), 14);
+        assert_eq!(r(5, 2).len(), 0);
+        assert!(r(1, 3).contains(1) && !r(1, 3).contains(3));
+
+        assert_eq!(r(0, 10).intersection(&r(5, 15)), Some(r(5, 10)));
+        assert_eq!(r(0, 5).intersection(&r(5, 15)), None);
+
+        assert_eq!(r(0, 10).difference(&r(3, 5)), vec![r(0, 3), r(5, 10)]);
+        assert_eq!(r(0, 10).difference(&r(0, 15)), vec![]);
+        assert_eq!(r(0, 10).difference(&r(20, 25)), vec![r(0, 10)]);
+
+        assert_eq!(r(0, 5).union(&r(5, 8)), Some(r(0, 8)));
+        assert_eq!(r(0, 5).union(&r(6, 8)), None);
+
+        assert_eq!(
+            r(0, 10).split([5, 0, 3, 12, 5]),
+            vec![r(0, 3), r(3, 5), r(5, 10)]
+        );
+    }
+
+    #[test]
+    fn test_union_all() {
+        assert_eq!(
+            union_all([r(5, 8), r(0, 2), r(1, 3), r(3, 4), r(9, 9)]),
+            vec![r(0, 4), r(5, 8)]
+        );
+    }
+
+    #[test]
+    fn test_range_map() {
+        // seed-to-soil map of the 2023 day 5 example
+        let map: RangeMap<u64> = [(r(98, 100), 50), (r(50, 98), 52)].into_iter().collect();
+
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(10), 10);
+
+        assert_eq!(
+            map.map_range(r(45, 105)),
+            vec![r(45, 50), r(52, 100), r(50, 52), r(100, 105)]
+        );
+        assert_eq!(
+            map.map_ranges([r(79, 93), r(55, 68)]),
+            vec![r(57, 70), r(81, 95)]
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+
+use serde::Deserialize;
+
+/// Environment variable holding the session cookie used to fetch private leaderboards
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Export of a private leaderboard, as served by
+/// `https://adventofcode.com/<year>/leaderboard/private/view/<id>.json`
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Anonymous members have no name
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Stars obtained, by day then by part
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        self.completion_day_level.get(&day)?.get(&part)
+    }
+}
+
+/// How points are awarded for each star
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scoring {
+    /// The first member to get a star earns as many points as there are members, the next
+    /// one point less, and so on, as on the site
+    Local,
+    /// The first `n` members to get a star earn `n` points down to 1, like the global leaderboard
+    Top(u64),
+    /// Every star is worth one point, whenever it was obtained
+    Stars,
+}
+
+impl FromStr for Scoring {
+    type Err = String;
+
+    /// Parses `local`, `stars` or `top:<n>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Scoring::Local),
+            "stars" => Ok(Scoring::Stars),
+            _ => s
+                .strip_prefix("top:")
+                .and_then(|n| n.parse().ok())
+                .map(Scoring::Top)
+                .ok_or_else(|| format!("unknown scoring {s}, expected local, stars or top:<n>")),
+        }
+    }
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        serde_json::from_str(json).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Downloads the leaderboard, authenticating with the session cookie of a member
+    #[cfg(feature = "fetch")]
+    pub fn fetch(url: &str, session: &str) -> io::Result<Self> {
+        let json = ureq::get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call()
+            .map_err(io::Error::other)?
+            .into_string()?;
+
+        Self::from_json(&json)
+    }
+
+    pub fn year(&self) -> i64 {
+        self.event.parse().unwrap_or(2023)
+    }
+
+    /// Seconds each member took to get the star since the puzzle unlocked, fastest first
+    pub fn solve_times(&self, day: u8, part: u8) -> Vec<(&Member, i64)> {
+        let unlock = unlock_ts(self.year(), day);
+
+        let mut times: Vec<(&Member, i64, u64)> = self
+            .members
+            .values()
+            .filter_map(|m| {
+                let star = m.star(day, part)?;
+                Some((m, star.get_star_ts - unlock, star.star_index))
+            })
+            .collect();
+
+        // the star index breaks ties between stars obtained in the same second
+        times.sort_by_key(|(_, time, index)| (*time, *index));
+
+        times.into_iter().map(|(m, time, _)| (m, time)).collect()
+    }
+
+    /// Seconds each member took between the first and the second star of the day, fastest first
+    pub fn deltas(&self, day: u8) -> Vec<(&Member, i64)> {
+        let mut deltas: Vec<(&Member, i64)> = self
+            .members
+            .values()
+            .filter_map(|m| {
+                let delta = m.star(day, 2)?.get_star_ts - m.star(day, 1)?.get_star_ts;
+                Some((m, delta))
+            })
+            .collect();
+
+        deltas.sort_by_key(|(_, delta)| *delta);
+
+        deltas
+    }
+
+    /// Days for which at least one member has a star
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .values()
+            .flat_map(|m| m.completion_day_level.keys().copied())
+            .collect();
+
+        days.sort();
+        days.dedup();
+
+        days
+    }
+
+    /// Recomputes the score of every member under the given rule, best first
+    pub fn scores(&self, scoring: Scoring) -> Vec<(&Member, u64)> {
+        let members_count = self.members.len() as u64;
+        let mut scores: BTreeMap<u64, u64> = self.members.values().map(|m| (m.id, 0)).collect();
+
+        for day in self.days() {
+            for part in 1..=2 {
+                for (rank, (member, _)) in self.solve_times(day, part).into_iter().enumerate() {
+                    let rank = rank as u64;
+
+                    let points = match scoring {
+                        Scoring::Local => members_count - rank,
+                        Scoring::Top(n) => n.saturating_sub(rank),
+                        Scoring::Stars => 1,
+                    };
+
+                    *scores.get_mut(&member.id).unwrap() += points;
+                }
+            }
+        }
+
+        let mut scores: Vec<(&Member, u64)> =
+            self.members.values().map(|m| (m, scores[&m.id])).collect();
+
+        scores.sort_by(|(a, a_score), (b, b_score)| b_score.cmp(a_score).then(a.id.cmp(&b.id)));
+
+        scores
+    }
+
+    /// Solve time rankings of every part of every day
+    pub fn rankings_report(&self) -> String {
+        let mut report = String::new();
+
+        for day in self.days() {
+            let _ = writeln!(report, "Day {day}");
+
+            for part in 1..=2 {
+                let _ = writeln!(report, "  Part {part}");
+
+                for (rank, (member, time)) in self.solve_times(day, part).iter().enumerate() {
+                    let _ = writeln!(
+                        report,
+                        "  {:>3}. {:>10}  {}",
+                        rank + 1,
+                        Elapsed(*time),
+                        member.display_name()
+                    );
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Time between both stars of every day
+    pub fn deltas_report(&self) -> String {
+        let mut report = String::new();
+
+        for day in self.days() {
+            let _ = writeln!(report, "Day {day}");
+
+            for (rank, (member, delta)) in self.deltas(day).iter().enumerate() {
+                let _ = writeln!(
+                    report,
+                    "  {:>3}. {:>10}  {}",
+                    rank + 1,
+                    Elapsed(*delta),
+                    member.display_name()
+                );
+            }
+        }
+
+        report
+    }
+
+    pub fn scores_report(&self, scoring: Scoring) -> String {
+        let mut report = String::new();
+
+        for (rank, (member, score)) in self.scores(scoring).iter().enumerate() {
+            let _ = writeln!(
+                report,
+                "{:>3}. {score:>5}  {:<2} {}",
+                rank + 1,
+                member.stars,
+                member.display_name()
+            );
+        }
+
+        report
+    }
+}
+
+/// Timestamp at which the puzzle of the day unlocks: midnight US Eastern time, 05:00 UTC
+pub fn unlock_ts(year: i64, day: u8) -> i64 {
+    (days_from_civil(year, 12, day as i64) * 24 + 5) * 3600
+}
+
+/// Days since 1970-01-01 of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// A number of seconds, formatted as `h:mm:ss`, or `>24h` past a day like on the site
+struct Elapsed(i64);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatted = if self.0 >= 24 * 3600 {
+            ">24h".to_owned()
+        } else {
+            format!(
+                "{}:{:02}:{:02}",
+                self.0 / 3600,
+                self.0 / 60 % 60,
+                self.0 % 60
+            )
+        };
+
+        f.pad(&formatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leaderboard::{unlock_ts, Elapsed, Leaderboard, Scoring};
+
+    const FIXTURE: &str = include_str!("../fixtures/leaderboard.json");
+
+    fn names(members: Vec<(&crate::leaderboard::Member, impl Copy)>) -> Vec<String> {
+        members.into_iter().map(|(m, _)| m.display_name()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::from_json(FIXTURE).unwrap();
+
+        assert_eq!(leaderboard.members.len(), 4);
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+        assert_eq!(
+            leaderboard.members["2"].display_name(),
+            "(anonymous user #2)"
+        );
+        assert_eq!(
+            leaderboard.members["1"].star(2, 2).unwrap().get_star_ts,
+            1701497200
+        );
+
+        assert!(Leaderboard::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_unlock_ts() {
+        assert_eq!(unlock_ts(2023, 1), 1701406800);
+        assert_eq!(unlock_ts(2023, 25), 1703480400);
+        assert_eq!(unlock_ts(2015, 1), 1448946000);
+    }
+
+    #[test]
+    fn test_solve_times() {
+        let leaderboard = Leaderboard::from_json(FIXTURE).unwrap();
+
+        let times = leaderboard.solve_times(1, 1);
+        assert_eq!(
+            times.iter().map(|(_, t)| *t).collect::<Vec<_>>(),
+            vec![200, 300, 3600]
+        );
+        assert_eq!(names(times), vec!["(anonymous user #2)", "Alice", "Carol"]);
+
+        assert_eq!(names(leaderboard.solve_times(2, 2)), vec!["Alice"]);
+    }
+
+    #[test]
+    fn test_deltas() {
+        let leaderboard = Leaderboard::from_json(FIXTURE).unwrap();
+
+        let deltas = leaderboard.deltas(1);
+        assert_eq!(
+            deltas.iter().map(|(_, d)| *d).collect::<Vec<_>>(),
+            vec![300, 700]
+        );
+        assert_eq!(names(deltas), vec!["Alice", "(anonymous user #2)"]);
+    }
+
+    #[test]
+    fn test_scores() {
+        let leaderboard = Leaderboard::from_json(FIXTURE).unwrap();
+
+        // the local scoring matches the scores computed by the site
+        for (member, score) in leaderboard.scores(Scoring::Local) {
+            assert_eq!(score, member.local_score);
+        }
+
+        let scores = |scoring| {
+            leaderboard
+                .scores(scoring)
+                .into_iter()
+                .map(|(m, s)| (m.id, s))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(scores(Scoring::Stars), vec![(1, 4), (2, 3), (3, 1), (4, 0)]);
+        assert_eq!(
+            scores(Scoring::Top(1)),
+            vec![(1, 3), (2, 1), (3, 0), (4, 0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_scoring() {
+        assert_eq!("local".parse(), Ok(Scoring::Local));
+        assert_eq!("top:100".parse(), Ok(Scoring::Top(100)));
+        assert!("top:".parse::<Scoring>().is_err());
+        assert!("global".parse::<Scoring>().is_err());
+    }
+
+    #[test]
+    fn test_elapsed() {
+        assert_eq!(Elapsed(3725).to_string(), "1:02:05");
+        assert_eq!(Elapsed(90000).to_string(), ">24h");
+    }
+}
@@ -1,18 +1,28 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-    path::Path,
-};
+pub mod alloc;
+pub mod bitset;
+pub mod config;
+pub mod crypto;
+mod day;
+pub mod geometry;
+pub mod graph;
+mod input;
+pub mod interval;
+pub mod leaderboard;
+pub mod lines;
+pub mod matcher;
+pub mod math;
+pub mod memo;
+pub mod phase;
+#[cfg(feature = "puzzle")]
+pub mod puzzle;
+pub mod share;
+pub mod snapshot;
+pub mod text;
+pub mod trace;
 
+pub use day::{known_answers, param, run, Day, Solver};
+pub use input::read_input;
 pub use lazy_static::lazy_static;
+pub use lines::read_lines;
 pub use regex::Regex;
-
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
+pub use tracing;
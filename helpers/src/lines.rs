@@ -0,0 +1,158 @@
+//! Line reader for inputs, streaming files, gzip files or stdin.
+//!
+//! It strips `\r\n` like `\n`, and reports errors with the number of the offending line instead
+//! of letting callers skip it.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, ErrorKind},
+    path::Path,
+    str,
+};
+
+use flate2::bufread::MultiGzDecoder;
+
+/// Path standing for the standard input
+pub const STDIN_PATH: &str = "-";
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Streams the lines of a file, of the standard input when the path is `-`. Gzip files are
+/// recognised by their content and decompressed on the fly.
+pub fn read_lines(path: impl AsRef<Path>) -> io::Result<Lines> {
+    let path = path.as_ref();
+
+    let reader: Box<dyn BufRead> = if path == Path::new(STDIN_PATH) {
+        Box::new(io::stdin().lock())
+    } else {
+        let file = File::open(path).map_err(|e| {
+            io::Error::new(e.kind(), format!("cannot open {}: {e}", path.display()))
+        })?;
+
+        Box::new(BufReader::new(file))
+    };
+
+    Lines::new(reader)
+}
+
+/// Lines of a reader, without their line ending
+pub struct Lines {
+    reader: Box<dyn BufRead>,
+    buf: Vec<u8>,
+    /// Number of the last line read, starting at 1
+    line: usize,
+    failed: bool,
+}
+
+impl Lines {
+    /// Reads the lines of `reader`, decompressing it if it starts like a gzip stream
+    pub fn new(mut reader: Box<dyn BufRead>) -> io::Result<Self> {
+        if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            reader = Box::new(BufReader::new(MultiGzDecoder::new(reader)));
+        }
+
+        Ok(Self {
+            reader,
+            buf: vec![],
+            line: 0,
+            failed: false,
+        })
+    }
+
+    fn error(&mut self, e: impl ToString, kind: ErrorKind) -> io::Error {
+        // nothing sensible can follow a broken line
+        self.failed = true;
+
+        io::Error::new(kind, format!("line {}: {}", self.line, e.to_string()))
+    }
+}
+
+impl Iterator for Lines {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        self.buf.clear();
+        self.line += 1;
+
+        match self.reader.read_until(b'\n', &mut self.buf) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(self.error(&e, e.kind()))),
+        }
+
+        let line = strip_line_ending(&self.buf);
+
+        Some(match str::from_utf8(line) {
+            Ok(line) => Ok(line.to_owned()),
+            Err(e) => Err(self.error(e, ErrorKind::InvalidData)),
+        })
+    }
+}
+
+fn strip_line_ending(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use crate::lines::{read_lines, Lines};
+
+    fn lines(content: &[u8]) -> Vec<io::Result<String>> {
+        Lines::new(Box::new(Cursor::new(content.to_vec())))
+            .unwrap()
+            .collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines: Vec<String> = lines(b"one\r\ntwo\n\nthree")
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(lines, vec!["one", "two", "", "three"]);
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        let lines = lines(b"one\ntw\xffo\nthree\n");
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .starts_with("line 2: invalid utf-8"));
+    }
+
+    #[test]
+    fn test_gzip() {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(b"one\r\ntwo\n").unwrap();
+
+        let lines: Vec<String> = lines(&encoder.finish().unwrap())
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(lines, vec!["one", "two"]);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let error = read_lines("missing/input.txt").err().unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().contains("missing/input.txt"));
+    }
+}
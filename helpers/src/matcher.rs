@@ -0,0 +1,198 @@
+//! Searches many patterns at once, in a single pass over the text, with an Aho-Corasick
+//! automaton. Unlike a regex, overlapping matches are all reported: `twone` contains both `two`
+//! and `one`.
+
+use std::collections::{HashMap, VecDeque};
+
+/// Set of patterns, each associated with a value
+#[derive(Debug, Clone)]
+pub struct Matcher<V> {
+    nodes: Vec<Node>,
+    /// Length and value of each pattern, by pattern index
+    patterns: Vec<(usize, V)>,
+}
+
+/// State of the automaton: a prefix of at least one pattern
+#[derive(Debug, Clone, Default)]
+struct Node {
+    next: HashMap<u8, usize>,
+    /// Longest proper suffix of this prefix that is also a prefix of some pattern
+    fail: usize,
+    /// Patterns ending here, longest first, including the ones ending at the fail nodes
+    outputs: Vec<usize>,
+}
+
+/// Occurrence of a pattern, `start..end` being its byte range in the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a, V> {
+    pub start: usize,
+    pub end: usize,
+    pub value: &'a V,
+}
+
+impl<V> Matcher<V> {
+    pub fn new<P: AsRef<str>>(table: impl IntoIterator<Item = (P, V)>) -> Self {
+        let mut nodes = vec![Node::default()];
+        let mut patterns = vec![];
+
+        // build the trie of the patterns
+        for (pattern, value) in table {
+            let pattern = pattern.as_ref();
+            let mut current = 0;
+
+            for byte in pattern.bytes() {
+                current = match nodes[current].next.get(&byte) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[current].next.insert(byte, next);
+                        next
+                    }
+                };
+            }
+
+            nodes[current].outputs.push(patterns.len());
+            patterns.push((pattern.len(), value));
+        }
+
+        // then link each node to its longest proper suffix, shallow nodes first as deeper ones
+        // rely on them
+        let mut queue: VecDeque<usize> = nodes[0].next.values().copied().collect();
+
+        while let Some(current) = queue.pop_front() {
+            let transitions: Vec<(u8, usize)> =
+                nodes[current].next.iter().map(|(b, n)| (*b, *n)).collect();
+
+            for (byte, next) in transitions {
+                let mut fail = nodes[current].fail;
+
+                let next_fail = loop {
+                    if let Some(&target) = nodes[fail].next.get(&byte) {
+                        break target;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = nodes[fail].fail;
+                };
+
+                nodes[next].fail = next_fail;
+
+                let inherited = nodes[next_fail].outputs.clone();
+                nodes[next].outputs.extend(inherited);
+
+                queue.push_back(next);
+            }
+        }
+
+        Self { nodes, patterns }
+    }
+
+    fn step(&self, mut current: usize, byte: u8) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[current].next.get(&byte) {
+                return next;
+            }
+            if current == 0 {
+                return 0;
+            }
+            current = self.nodes[current].fail;
+        }
+    }
+
+    /// Every occurrence of every pattern, overlapping ones included, by end position then longest
+    /// first
+    pub fn find_overlapping<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> impl Iterator<Item = Match<'a, V>> + 'a {
+        text.bytes()
+            .enumerate()
+            .scan(0, |current, (i, byte)| {
+                *current = self.step(*current, byte);
+                Some((i + 1, *current))
+            })
+            .flat_map(move |(end, current)| {
+                self.nodes[current].outputs.iter().map(move |&pattern| {
+                    let (len, value) = &self.patterns[pattern];
+
+                    Match {
+                        start: end - len,
+                        end,
+                        value,
+                    }
+                })
+            })
+    }
+
+    /// Occurrence starting first, the longest one if several patterns start there
+    pub fn leftmost<'a>(&'a self, text: &'a str) -> Option<Match<'a, V>> {
+        let max_len = self.patterns.iter().map(|(len, _)| *len).max()?;
+
+        let mut best: Option<Match<V>> = None;
+
+        for m in self.find_overlapping(text) {
+            // no later match can start before the best one
+            if best.as_ref().is_some_and(|b| m.end > b.start + max_len) {
+                break;
+            }
+
+            if best
+                .as_ref()
+                .is_none_or(|b| (m.start, b.end) < (b.start, m.end))
+            {
+                best = Some(m);
+            }
+        }
+
+        best
+    }
+
+    /// Occurrence starting last, the longest one if several patterns start there
+    pub fn rightmost<'a>(&'a self, text: &'a str) -> Option<Match<'a, V>> {
+        self.find_overlapping(text).max_by_key(|m| (m.start, m.end))
+    }
+}
+
+impl<P: AsRef<str>, V> FromIterator<(P, V)> for Matcher<V> {
+    fn from_iter<I: IntoIterator<Item = (P, V)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::Matcher;
+
+    #[test]
+    fn test_find_overlapping() {
+        let matcher = Matcher::new([("he", 1), ("she", 2), ("his", 3), ("hers", 4)]);
+
+        let matches: Vec<(usize, usize, i32)> = matcher
+            .find_overlapping("ushers")
+            .map(|m| (m.start, m.end, *m.value))
+            .collect();
+
+        assert_eq!(matches, vec![(1, 4, 2), (2, 4, 1), (2, 6, 4)]);
+
+        assert_eq!(matcher.find_overlapping("").count(), 0);
+        assert_eq!(matcher.find_overlapping("xyz").count(), 0);
+    }
+
+    #[test]
+    fn test_leftmost_rightmost() {
+        let matcher: Matcher<u32> = [("one", 1), ("two", 2), ("eight", 8), ("ei", 0)]
+            .into_iter()
+            .collect();
+
+        let leftmost = matcher.leftmost("xeightwone").unwrap();
+        assert_eq!((leftmost.start, leftmost.end, *leftmost.value), (1, 6, 8));
+
+        let rightmost = matcher.rightmost("xeightwone").unwrap();
+        assert_eq!((rightmost.start, *rightmost.value), (7, 1));
+
+        assert_eq!(matcher.leftmost("nothing"), None);
+        assert_eq!(Matcher::<u32>::new::<&str>([]).leftmost("one"), None);
+    }
+}
@@ -0,0 +1,247 @@
+//! Number theory on machine integers, for puzzles about cycles and periods.
+//!
+//! Functions named `checked_*` return an error where the plain ones would overflow.
+
+use std::{error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    /// The result does not fit in the integer type
+    Overflow,
+    /// The congruences contradict each other, or the number has no inverse
+    NoSolution,
+    /// A modulus is zero or negative
+    InvalidModulus,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MathError::Overflow => "arithmetic overflow",
+            MathError::NoSolution => "no solution",
+            MathError::InvalidModulus => "modulus must be positive",
+        })
+    }
+}
+
+impl error::Error for MathError {}
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, panicking on overflow
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).expect("lcm overflows u64")
+}
+
+pub fn checked_lcm(a: u64, b: u64) -> Result<u64, MathError> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b).ok_or(MathError::Overflow)
+}
+
+/// Greatest common divisor of all the numbers, 0 for none
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of all the numbers, 1 for none, panicking on overflow
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    checked_lcm_all(numbers).expect("lcm overflows u64")
+}
+
+pub fn checked_lcm_all(numbers: impl IntoIterator<Item = u64>) -> Result<u64, MathError> {
+    numbers.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Extended Euclid: `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`, `g` being
+/// non-negative. Overflows when the gcd is 2^63, for `i64::MIN` and 0 or `i64::MIN` itself.
+pub fn extended_gcd(a: i64, b: i64) -> Result<(i64, i64, i64), MathError> {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+
+    let narrow = |n: i128| i64::try_from(n).map_err(|_| MathError::Overflow);
+
+    Ok((narrow(g)?, narrow(x)?, narrow(y)?))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Inverse of `a` modulo `modulus`, in `0..modulus`
+pub fn mod_inverse(a: i64, modulus: i64) -> Result<i64, MathError> {
+    if modulus <= 0 {
+        return Err(MathError::InvalidModulus);
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus)?;
+
+    if g != 1 {
+        return Err(MathError::NoSolution);
+    }
+
+    Ok(x.rem_euclid(modulus))
+}
+
+/// Chinese Remainder Theorem generalised to moduli that are not pairwise coprime.
+///
+/// Solves `x = residue (mod modulus)` for every `(residue, modulus)` pair, returning the
+/// smallest non-negative solution and the modulus of all the solutions, the lcm of the moduli.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Result<(i64, i64), MathError> {
+    let mut solution: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for (residue, m) in congruences {
+        if m <= 0 {
+            return Err(MathError::InvalidModulus);
+        }
+
+        let (residue, m) = (residue as i128, m as i128);
+
+        // solution + modulus * k = residue (mod m) has a solution iff g divides the difference
+        let (g, inverse, _) = extended_gcd_wide(modulus, m);
+        let difference = residue - solution;
+
+        if difference % g != 0 {
+            return Err(MathError::NoSolution);
+        }
+
+        let reduced = m / g;
+        let k = (difference / g % reduced * inverse).rem_euclid(reduced);
+
+        solution += modulus * k;
+        modulus *= reduced;
+
+        if modulus > i64::MAX as i128 {
+            return Err(MathError::Overflow);
+        }
+
+        solution = solution.rem_euclid(modulus);
+    }
+
+    Ok((solution as i64, modulus as i64))
+}
+
+/// Largest integer whose square is at most `n`
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // the float estimate is off by at most one either way for large numbers
+    let mut root = (n as f64).sqrt() as u64;
+
+    while root.checked_mul(root).is_none_or(|square| square > n) {
+        root -= 1;
+    }
+    while (root + 1)
+        .checked_mul(root + 1)
+        .is_some_and(|square| square <= n)
+    {
+        root += 1;
+    }
+
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{
+        checked_lcm, checked_lcm_all, crt, extended_gcd, gcd, gcd_all, isqrt, lcm_all, mod_inverse,
+        MathError,
+    };
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2, 3, 4]), 12);
+        assert_eq!(lcm_all([]), 1);
+
+        assert_eq!(
+            checked_lcm(u64::MAX, u64::MAX - 1),
+            Err(MathError::Overflow)
+        );
+        assert_eq!(
+            checked_lcm_all([1 << 40, 3 << 30, 5 << 20, 7]),
+            Ok(105 << 40)
+        );
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [
+            (240, 46),
+            (-240, 46),
+            (7, 0),
+            (0, 0),
+            (i64::MAX, i64::MAX - 1),
+        ] {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+
+            assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+        }
+
+        // the gcd, 2^63, does not fit
+        assert_eq!(extended_gcd(i64::MIN, 0), Err(MathError::Overflow));
+        assert_eq!(extended_gcd(i64::MIN, i64::MIN), Err(MathError::Overflow));
+        assert!(extended_gcd(i64::MIN, 1).is_ok());
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Ok(4));
+        assert_eq!(mod_inverse(-3, 11), Ok(7));
+        assert_eq!(mod_inverse(4, 8), Err(MathError::NoSolution));
+        assert_eq!(mod_inverse(4, 0), Err(MathError::InvalidModulus));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        // moduli sharing factors
+        assert_eq!(crt([(3, 4), (5, 6)]), Ok((11, 12)));
+        assert_eq!(crt([(0, 2), (1, 4)]), Err(MathError::NoSolution));
+        assert_eq!(crt([(-1, 5)]), Ok((4, 5)));
+        assert_eq!(crt([]), Ok((0, 1)));
+
+        assert_eq!(
+            crt([(1, i64::MAX), (0, i64::MAX - 1)]),
+            Err(MathError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt((1 << 62) - 1), (1 << 31) - 1);
+    }
+}
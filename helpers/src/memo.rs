@@ -0,0 +1,151 @@
+//! Caches the results of pure recursive functions.
+//!
+//! The recursive function takes the cache as a parameter and wraps its body in
+//! [`Memo::get_or_insert_with`], recursing through the cache it is given:
+//!
+//! ```
+//! use helpers::memo::Memo;
+//!
+//! fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+//!     memo.get_or_insert_with(n, |memo| {
+//!         if n < 2 {
+//!             n
+//!         } else {
+//!             fibonacci(memo, n - 1) + fibonacci(memo, n - 2)
+//!         }
+//!     })
+//! }
+//!
+//! let mut memo = Memo::new();
+//! assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+//! assert_eq!(memo.stats().misses, 91);
+//! ```
+
+use std::{collections::HashMap, fmt, hash::Hash};
+
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+/// How useful a cache has been
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoStats {
+    /// Calls answered from the cache
+    pub hits: u64,
+    /// Calls that had to compute their result
+    pub misses: u64,
+    /// Results currently cached
+    pub size: usize,
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} entries",
+            self.hits, self.misses, self.size
+        )
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the cached result for `key`, or computes it with `compute`, which is given the
+    /// cache back to recurse through it
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+
+        value
+    }
+
+    /// Drops the cached results, to reuse the cache for unrelated arguments. Statistics are kept.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.cache.len(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shows the statistics rather than the cached results, which can be numerous
+impl<K, V> fmt::Debug for Memo<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memo")
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .field("size", &self.cache.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memo::{Memo, MemoStats};
+
+    /// Paths from the top left to the bottom right corner of a grid, moving right or down
+    fn paths(memo: &mut Memo<(u64, u64), u64>, width: u64, height: u64) -> u64 {
+        memo.get_or_insert_with((width, height), |memo| {
+            if width == 0 || height == 0 {
+                1
+            } else {
+                paths(memo, width - 1, height) + paths(memo, width, height - 1)
+            }
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+
+        assert_eq!(paths(&mut memo, 16, 16), 601080390);
+
+        let stats = memo.stats();
+        assert_eq!(stats.misses, stats.size as u64);
+        assert_eq!(stats.size, 17 * 17 - 1);
+        assert!(stats.hits > 0);
+
+        assert_eq!(
+            format!("{memo:?}"),
+            format!("Memo {{ hits: {}, misses: 288, size: 288 }}", stats.hits)
+        );
+
+        memo.clear();
+        assert_eq!(paths(&mut memo, 0, 3), 1);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: stats.hits,
+                misses: 289,
+                size: 1
+            }
+        );
+    }
+}
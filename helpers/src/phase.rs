@@ -0,0 +1,56 @@
+//! Phases of a solver, parsing its input then solving the puzzle, each run in a span named after
+//! it, nested in the span of the part:
+//!
+//! ```ignore
+//! let maze = phase::parse(|| parse_maze(input));
+//! phase::solve(|| farthest_tile(&maze))
+//! ```
+//!
+//! The allocations of the parsing are recorded for the runner, which reports them apart from
+//! those of the whole part.
+
+use std::cell::Cell;
+
+use crate::alloc::{self, AllocStats};
+
+thread_local! {
+    /// Allocations of the parsing phases run on this thread since last taken
+    static PARSE_STATS: Cell<Option<AllocStats>> = const { Cell::new(None) };
+}
+
+/// Runs the parsing of the input in a `parse` span
+pub fn parse<T>(f: impl FnOnce() -> T) -> T {
+    let (res, stats) = alloc::measure(|| tracing::info_span!("parse").in_scope(f));
+
+    PARSE_STATS.with(|parse| parse.set(Some(parse.get().map_or(stats, |s| s.then(stats)))));
+
+    res
+}
+
+/// Runs the solving of the puzzle in a `solve` span
+pub fn solve<T>(f: impl FnOnce() -> T) -> T {
+    tracing::info_span!("solve").in_scope(f)
+}
+
+/// Allocations of the parsing phases run on this thread since the last call, `None` if there
+/// was none
+pub fn take_parse_stats() -> Option<AllocStats> {
+    PARSE_STATS.with(Cell::take)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::phase::{parse, solve, take_parse_stats};
+
+    #[test]
+    fn test_parse_stats() {
+        take_parse_stats();
+
+        let values: Vec<u64> = parse(|| (0..10).collect());
+        assert_eq!(solve(|| values.iter().sum::<u64>()), 45);
+
+        // the global allocator of the helpers tests counts the collected vector
+        assert!(take_parse_stats().unwrap().allocations >= 1);
+        assert_eq!(take_parse_stats(), None);
+    }
+}
@@ -0,0 +1,261 @@
+//! Puzzle statements, saved from `https://adventofcode.com/<year>/day/<day>` next to the inputs
+//! and converted to Markdown to be read in the terminal.
+//!
+//! Only the articles of the page are kept: the first part, and the second one once unlocked.
+
+use std::io::{self, ErrorKind};
+
+use scraper::{node::Node, ElementRef, Html, Selector};
+
+/// Base of the links of the statements, which are relative to the site
+const SITE: &str = "https://adventofcode.com";
+
+/// Statement of a puzzle page in Markdown, one section per unlocked part
+pub fn to_markdown(html: &str, width: usize) -> io::Result<String> {
+    let document = Html::parse_document(html);
+    let articles = Selector::parse("article.day-desc").unwrap();
+
+    let parts: Vec<String> = document
+        .select(&articles)
+        .map(|article| {
+            let mut markdown = String::new();
+            blocks(article, width, &mut markdown);
+            markdown.trim_end().to_owned()
+        })
+        .collect();
+
+    if parts.is_empty() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            "no puzzle statement in the page",
+        ));
+    }
+
+    Ok(format!("{}\n", parts.join("\n\n")))
+}
+
+/// Number of parts of the statement in a puzzle page, 2 once the first one is solved
+pub fn parts(html: &str) -> usize {
+    let articles = Selector::parse("article.day-desc").unwrap();
+
+    Html::parse_document(html).select(&articles).count()
+}
+
+/// Downloads the page of a puzzle, with its second part if the session has solved the first one
+#[cfg(feature = "fetch")]
+pub fn fetch(year: u16, day: u8, session: Option<&str>) -> io::Result<String> {
+    let mut request = ureq::get(&format!("{SITE}/{year}/day/{day}"))
+        .set("User-Agent", "github.com/fargito/aoc-2023 puzzle viewer");
+
+    if let Some(session) = session {
+        request = request.set("Cookie", &format!("session={session}"));
+    }
+
+    request.call().map_err(io::Error::other)?.into_string()
+}
+
+/// Appends the blocks among the children of `parent`, each followed by a blank line
+fn blocks(parent: ElementRef, width: usize, out: &mut String) {
+    for child in parent.children().filter_map(ElementRef::wrap) {
+        match child.value().name() {
+            "h2" => {
+                // `--- Day 9: Mirage Maintenance ---`
+                let title = inline(child);
+                let title = title.trim().trim_matches('-').trim();
+                out.push_str(&format!("## {title}\n\n"));
+            }
+            "pre" => {
+                let code: String = child.text().collect();
+                out.push_str(&format!("```\n{}\n```\n\n", code.trim_end_matches('\n')));
+            }
+            "ul" | "ol" => {
+                list(child, 0, width, out);
+                out.push('\n');
+            }
+            "blockquote" => {
+                let mut quote = String::new();
+                blocks(child, width.saturating_sub(2), &mut quote);
+
+                for line in quote.trim_end().lines() {
+                    out.push_str(format!("> {line}").trim_end());
+                    out.push('\n');
+                }
+                out.push('\n');
+            }
+            _ => {
+                out.push_str(&wrap(&inline(child), "", "", width));
+                out.push_str("\n\n");
+            }
+        }
+    }
+}
+
+/// Appends the items of a list, nested lists indented under their item
+fn list(element: ElementRef, depth: usize, width: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    let ordered = element.value().name() == "ol";
+
+    for (i, item) in (1..).zip(element.children().filter_map(ElementRef::wrap)) {
+        let marker = match ordered {
+            true => format!("{i}. "),
+            false => "- ".to_owned(),
+        };
+
+        // the text of the item, then its nested lists
+        let text: String = item
+            .children()
+            .filter(|child| {
+                ElementRef::wrap(*child).is_none_or(|e| !matches!(e.value().name(), "ul" | "ol"))
+            })
+            .map(inline_node)
+            .collect();
+
+        let first = format!("{indent}{marker}");
+        let rest = " ".repeat(first.len());
+        out.push_str(&wrap(&text, &first, &rest, width));
+        out.push('\n');
+
+        for nested in item.children().filter_map(ElementRef::wrap) {
+            if matches!(nested.value().name(), "ul" | "ol") {
+                list(nested, depth + 1, width, out);
+            }
+        }
+    }
+}
+
+/// Text of an element with its inline markup, whitespace collapsed
+fn inline(element: ElementRef) -> String {
+    let text: String = element.children().map(inline_node).collect();
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn inline_node(node: ego_tree::NodeRef<Node>) -> String {
+    let element = match node.value() {
+        Node::Text(text) => return text.to_string(),
+        Node::Element(_) => ElementRef::wrap(node).unwrap(),
+        _ => return String::new(),
+    };
+
+    match element.value().name() {
+        "code" => {
+            let code: String = element.text().collect();
+            // answers are highlighted inside code, `<code><em>42</em></code>`
+            let highlighted = element
+                .children()
+                .filter_map(ElementRef::wrap)
+                .any(|e| e.value().name() == "em");
+
+            match highlighted {
+                true => format!("**`{code}`**"),
+                false => format!("`{code}`"),
+            }
+        }
+        "em" => format!("**{}**", inline(element)),
+        "a" => {
+            let href = element.value().attr("href").unwrap_or_default();
+            let href = match href.starts_with('/') {
+                true => format!("{SITE}{href}"),
+                false => href.to_owned(),
+            };
+
+            format!("[{}]({href})", inline(element))
+        }
+        "br" => "\n".to_owned(),
+        _ => element.children().map(inline_node).collect(),
+    }
+}
+
+/// Wraps text on spaces at `width` columns, the first line starting with `first` and the next
+/// ones with `rest`
+fn wrap(text: &str, first: &str, rest: &str, width: usize) -> String {
+    let mut lines = vec![];
+    let mut line = first.to_owned();
+    let mut empty = true;
+
+    for word in text.split_whitespace() {
+        if !empty && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(line);
+            line = rest.to_owned();
+            empty = true;
+        }
+
+        if !empty {
+            line.push(' ');
+        }
+
+        line.push_str(word);
+        empty = false;
+    }
+
+    lines.push(line);
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{parts, to_markdown};
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><head><title>Day 9 - Advent of Code 2023</title></head>
+<body><header><h1 class="title-global"><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2>
+<p>You ride the camel through the sandstorm and stop where the ghost's maps told you to stop.</p>
+<p>Each line in the <a href="/2023/day/9/input" target="_blank">report</a> is a <em>history</em>:</p>
+<pre><code>0 3 6 9 12 15
+1 3 6 10 15 21
+</code></pre>
+<ul>
+<li>Predict the <em>next</em> value;
+  <ul><li>using the differences.</li></ul>
+</li>
+<li>What is the sum of these values? It is <code><em>114</em></code>, <code>&lt;1&gt;</code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>1757008019</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Of course, it would be nice to have <span title="easter egg">even more history</span>.</p>
+</article>
+</main></body></html>
+"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE, 60).unwrap(),
+            "## Day 9: Mirage Maintenance\n\
+             \n\
+             You ride the camel through the sandstorm and stop where the\n\
+             ghost's maps told you to stop.\n\
+             \n\
+             Each line in the\n\
+             [report](https://adventofcode.com/2023/day/9/input) is a\n\
+             **history**:\n\
+             \n\
+             ```\n\
+             0 3 6 9 12 15\n\
+             1 3 6 10 15 21\n\
+             ```\n\
+             \n\
+             - Predict the **next** value;\n  \
+               - using the differences.\n\
+             - What is the sum of these values? It is **`114`**, `<1>`.\n\
+             \n\
+             ## Part Two\n\
+             \n\
+             Of course, it would be nice to have even more history.\n"
+        );
+        assert_eq!(parts(PAGE), 2);
+    }
+
+    #[test]
+    fn test_locked() {
+        assert_eq!(
+            parts("<main><p>Log in to get your puzzle input.</p></main>"),
+            0
+        );
+        assert!(to_markdown("<main><p>Log in</p></main>", 80).is_err());
+    }
+}
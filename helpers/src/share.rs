@@ -0,0 +1,153 @@
+//! Hashes of answers, for teammates to check that they got the same answer for the same input
+//! without revealing either.
+//!
+//! A hash covers the SHA-256 of the input, the day, the part and the answer, salted so that the
+//! hashes of a same answer differ. Its text form, `2023/d09:1:<salt>:<hash>`, carries the salt
+//! for the receiver to hash their own input and answer the same way.
+
+use std::{fmt, str::FromStr};
+
+use chacha20poly1305::aead::{rand_core::RngCore, OsRng};
+use sha2::{Digest, Sha256};
+
+use crate::Day;
+
+const SALT_LEN: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnswerHash {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    salt: [u8; SALT_LEN],
+    hash: [u8; 32],
+}
+
+impl AnswerHash {
+    /// Hash of an answer to a part, with a new salt
+    pub fn new(day: &Day, part: u8, input: &str, answer: &str) -> Self {
+        let mut salt = [0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        Self::with_salt(day.year, day.day, part, salt, input, answer)
+    }
+
+    fn with_salt(
+        year: u16,
+        day: u8,
+        part: u8,
+        salt: [u8; SALT_LEN],
+        input: &str,
+        answer: &str,
+    ) -> Self {
+        let hash = Sha256::new()
+            .chain_update(salt)
+            .chain_update(Sha256::digest(input))
+            .chain_update(year.to_be_bytes())
+            .chain_update([day, part])
+            .chain_update(answer.trim())
+            .finalize()
+            .into();
+
+        Self {
+            year,
+            day,
+            part,
+            salt,
+            hash,
+        }
+    }
+
+    /// Whether the hash was made from this input and answer
+    pub fn matches(&self, input: &str, answer: &str) -> bool {
+        Self::with_salt(self.year, self.day, self.part, self.salt, input, answer) == *self
+    }
+}
+
+impl fmt::Display for AnswerHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/d{:02}:{}:{}:{}",
+            self.year,
+            self.day,
+            self.part,
+            hex::encode(self.salt),
+            hex::encode(self.hash)
+        )
+    }
+}
+
+impl FromStr for AnswerHash {
+    type Err = String;
+
+    /// Parses `<year>/d<day>:<part>:<salt>:<hash>`, the part being 1 or 2
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || format!("invalid answer hash {s}, expected <year>/dNN:<part>:<salt>:<hash>");
+
+        let [day, part, salt, hash] = s.trim().split(':').collect::<Vec<_>>()[..] else {
+            return Err(invalid());
+        };
+
+        let (year, day) = day.split_once("/d").ok_or_else(invalid)?;
+        let part = part.parse().ok().filter(|p| (1..=2).contains(p));
+
+        Ok(Self {
+            year: year.parse().map_err(|_| invalid())?,
+            day: day.parse().map_err(|_| invalid())?,
+            part: part.ok_or_else(invalid)?,
+            salt: decode(salt).ok_or_else(invalid)?,
+            hash: decode(hash).ok_or_else(invalid)?,
+        })
+    }
+}
+
+fn decode<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    hex::decode(hex).ok()?.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{share::AnswerHash, Day};
+
+    const DAY: Day = Day {
+        year: 2023,
+        day: 9,
+        title: "Mirage Maintenance",
+        inputs_dir: "d09/inputs",
+        params: &[],
+        part_1: None,
+        part_2: None,
+    };
+
+    const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+    #[test]
+    fn test_matches() {
+        let hash = AnswerHash::new(&DAY, 1, INPUT, "114");
+
+        assert!(hash.matches(INPUT, "114"));
+        assert!(!hash.matches(INPUT, "115"));
+        assert!(!hash.matches("0 3 6 9 12 15\n", "114"));
+
+        // salted, so a same answer cannot be recognised
+        assert_ne!(hash, AnswerHash::new(&DAY, 1, INPUT, "114"));
+        assert!(!AnswerHash::new(&DAY, 2, INPUT, "114").matches(INPUT, "2"));
+    }
+
+    #[test]
+    fn test_text() {
+        let hash = AnswerHash::new(&DAY, 2, INPUT, "2");
+        let text = hash.to_string();
+
+        assert!(text.starts_with("2023/d09:2:"));
+        assert_eq!(text.parse::<AnswerHash>().unwrap(), hash);
+
+        assert!("2023/d09:2:abcd".parse::<AnswerHash>().is_err());
+        assert!(text.replace(":2:", ":x:").parse::<AnswerHash>().is_err());
+        assert!(text.replace(":2:", ":3:").parse::<AnswerHash>().is_err());
+        assert!(text.replace(":2:", ":0:").parse::<AnswerHash>().is_err());
+        assert!(text[..text.len() - 2].parse::<AnswerHash>().is_err());
+    }
+}
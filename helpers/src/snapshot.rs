@@ -0,0 +1,171 @@
+//! Snapshot tests: the `Display` or `Debug` form of a value is compared with the one stored in
+//! `dNN/tests/snapshots/<name>.snap`.
+//!
+//! A missing or different snapshot fails the test and leaves the new form next to it, as
+//! `<name>.snap.new`, for `runner snapshots` to show the difference and accept or reject it.
+
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use similar::TextDiff;
+
+/// Directory of the snapshots, relative to the crate
+pub const SNAPSHOTS_DIR: &str = "tests/snapshots";
+pub const SNAPSHOT_EXTENSION: &str = "snap";
+/// Extension of the snapshots waiting to be reviewed
+pub const PENDING_EXTENSION: &str = "snap.new";
+
+/// Compares the `Display` form of a value with its snapshot, see the [module](self)
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $value:expr) => {
+        $crate::snapshot::assert_snapshot(env!("CARGO_MANIFEST_DIR"), $name, &$value.to_string())
+    };
+}
+
+/// Compares the pretty `Debug` form of a value with its snapshot, see the [module](self)
+#[macro_export]
+macro_rules! assert_debug_snapshot {
+    ($name:expr, $value:expr) => {
+        $crate::snapshot::assert_snapshot(
+            env!("CARGO_MANIFEST_DIR"),
+            $name,
+            &format!("{:#?}", $value),
+        )
+    };
+}
+
+pub fn snapshot_path(crate_dir: &Path, name: &str) -> PathBuf {
+    crate_dir
+        .join(SNAPSHOTS_DIR)
+        .join(format!("{name}.{SNAPSHOT_EXTENSION}"))
+}
+
+/// Snapshot a pending one would replace
+pub fn accepted_path(pending: &Path) -> PathBuf {
+    pending.with_extension("")
+}
+
+/// Panics with the difference if `actual` is not the stored snapshot, after saving it aside
+pub fn assert_snapshot(crate_dir: &str, name: &str, actual: &str) {
+    let path = snapshot_path(Path::new(crate_dir), name);
+    let pending = path.with_extension(PENDING_EXTENSION);
+    let actual = normalise(actual);
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => Some(expected),
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => panic!("cannot read {}: {e}", path.display()),
+    };
+
+    if expected.as_deref() == Some(actual.as_str()) {
+        // a stale pending snapshot would be accepted over the good one
+        let _ = fs::remove_file(&pending);
+        return;
+    }
+
+    fs::create_dir_all(pending.parent().unwrap())
+        .and_then(|_| fs::write(&pending, &actual))
+        .unwrap_or_else(|e| panic!("cannot write {}: {e}", pending.display()));
+
+    match expected {
+        Some(expected) => panic!(
+            "snapshot {name} differs, review it with `cargo run -p runner -- snapshots`\n{}",
+            diff(&expected, &actual)
+        ),
+        None => panic!(
+            "snapshot {name} is new, review it with `cargo run -p runner -- snapshots`\n{actual}"
+        ),
+    }
+}
+
+/// Unified difference between two snapshots
+pub fn diff(expected: &str, actual: &str) -> String {
+    TextDiff::from_lines(expected, actual)
+        .unified_diff()
+        .header("snapshot", "new")
+        .to_string()
+}
+
+/// Snapshots waiting to be reviewed in a crate, sorted by name
+pub fn pending(crate_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let dir = crate_dir.join(SNAPSHOTS_DIR);
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut pending = vec![];
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path
+            .to_str()
+            .is_some_and(|p| p.ends_with(&format!(".{PENDING_EXTENSION}")))
+        {
+            pending.push(path);
+        }
+    }
+
+    pending.sort();
+
+    Ok(pending)
+}
+
+/// Replaces the snapshot with the pending one
+pub fn accept(pending: &Path) -> io::Result<()> {
+    fs::rename(pending, accepted_path(pending))
+}
+
+/// Snapshots end with exactly one newline, so that editors do not make them differ
+fn normalise(value: &str) -> String {
+    format!("{}\n", value.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, panic, path::Path};
+
+    use crate::snapshot::{accept, accepted_path, assert_snapshot, diff, pending};
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff("a\nb\nc\n", "a\nB\nc\n"),
+            "--- snapshot\n+++ new\n@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n"
+        );
+    }
+
+    #[test]
+    fn test_review() {
+        let dir = std::env::temp_dir().join(format!("aoc-snapshot-{}", std::process::id()));
+        let crate_dir = dir.to_str().unwrap();
+
+        // a new snapshot is left pending
+        let res = panic::catch_unwind(|| assert_snapshot(crate_dir, "pyramid", "0 3 6\n 3 3"));
+        assert!(res.is_err());
+
+        let new = pending(&dir).unwrap();
+        assert_eq!(new.len(), 1);
+        assert!(accepted_path(&new[0]).ends_with("tests/snapshots/pyramid.snap"));
+
+        accept(&new[0]).unwrap();
+        assert!(pending(&dir).unwrap().is_empty());
+
+        // then matches, whatever the trailing whitespace
+        assert_snapshot(crate_dir, "pyramid", "0 3 6\n 3 3\n\n");
+
+        let res = panic::catch_unwind(|| assert_snapshot(crate_dir, "pyramid", "0 3 7\n 3 4"));
+        assert!(res.is_err());
+        assert_eq!(pending(&dir).unwrap().len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(pending(Path::new(&dir)).unwrap().is_empty());
+    }
+}
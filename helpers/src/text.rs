@@ -0,0 +1,111 @@
+//! Example inputs written as indented string literals, normalised to look like real inputs.
+
+/// Removes the indentation shared by the lines of `text`, the leading and trailing blank lines,
+/// and the whitespace of blank lines, then ends the text with a newline if asked to.
+///
+/// The first line is left out of the shared indentation, as it follows the opening quote.
+pub fn dedent(text: &str, trailing_newline: bool) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+
+    let indent = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let lines: Vec<&str> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| match line.trim().is_empty() {
+            true => "",
+            false if i == 0 => line.trim_start(),
+            // the shared indentation is whitespace, so slicing lands on a char boundary
+            false => &line[indent..],
+        })
+        .collect();
+
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+
+    let mut res = match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].join("\n"),
+        _ => return String::new(),
+    };
+
+    if trailing_newline {
+        res.push('\n');
+    }
+
+    res
+}
+
+/// Example input of a test, dedented and ending with a newline like real inputs. Pass
+/// `trailing_newline = false` to end it on its last line.
+///
+/// ```
+/// let input = helpers::example!("
+///     0 3 6
+///     1 3 6
+/// ");
+///
+/// assert_eq!(input, "0 3 6\n1 3 6\n");
+/// ```
+#[macro_export]
+macro_rules! example {
+    ($text:expr) => {
+        $crate::text::dedent($text, true)
+    };
+    ($text:expr, trailing_newline = $newline:expr) => {
+        $crate::text::dedent($text, $newline)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::dedent;
+
+    #[test]
+    fn test_dedent() {
+        let text = "
+            RL
+
+            AAA = (BBB, CCC)
+              BBB = (DDD, EEE)
+            ";
+
+        assert_eq!(
+            dedent(text, true),
+            "RL\n\nAAA = (BBB, CCC)\n  BBB = (DDD, EEE)\n"
+        );
+        assert_eq!(
+            dedent(text, false),
+            "RL\n\nAAA = (BBB, CCC)\n  BBB = (DDD, EEE)"
+        );
+    }
+
+    #[test]
+    fn test_first_line() {
+        // the first line follows the opening quote, the others are indented
+        let text = "LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)";
+
+        assert_eq!(
+            dedent(text, true),
+            "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n"
+        );
+    }
+
+    #[test]
+    fn test_blank() {
+        assert_eq!(dedent("", true), "");
+        assert_eq!(dedent("\n   \n\t\n", true), "");
+        assert_eq!(
+            example!("  a\n   \n  b", trailing_newline = false),
+            "a\n\nb"
+        );
+    }
+}
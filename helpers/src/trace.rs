@@ -0,0 +1,178 @@
+//! Tracing of the solvers: loading the input and each part run in their own span, logged to
+//! stderr with their duration when they close.
+//!
+//! Spans and events are compiled out of release builds, so they cost nothing when measuring.
+
+use std::{
+    env,
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use tracing::level_filters::{LevelFilter, STATIC_MAX_LEVEL};
+use tracing_flame::{FlameLayer, FlushGuard};
+use tracing_subscriber::{fmt::format::FmtSpan, prelude::*, EnvFilter};
+
+/// Environment variable holding the log filter, e.g. `info` or `d12=trace`, taking precedence
+/// over the verbosity
+pub const LEVEL_ENV: &str = "AOC_LOG";
+/// Environment variable holding the file the folded stacks of the spans are appended to
+pub const FLAME_ENV: &str = "AOC_FLAME";
+
+/// Writes the remaining folded stacks when dropped
+pub struct TraceGuard(Option<FlushGuard<BufWriter<Prefixed<File>>>>);
+
+impl Drop for TraceGuard {
+    fn drop(&mut self) {
+        if let Some(guard) = &self.0 {
+            let _ = guard.flush();
+        }
+    }
+}
+
+/// Level of the logs for a number of `--verbose` flags
+pub fn level(verbosity: u8) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
+/// Installs the global subscriber, unless one already is.
+///
+/// Logs are filtered by `AOC_LOG` when set, else by the verbosity. Folded stacks, the input of
+/// flamegraph tools, are appended to the file in `AOC_FLAME` when set, each stack starting with
+/// `root` so that the stacks of several processes can share a file.
+pub fn init(verbosity: u8, root: &str) -> io::Result<TraceGuard> {
+    if !enabled() {
+        return Ok(TraceGuard(None));
+    }
+
+    let filter = match env::var(LEVEL_ENV) {
+        Ok(filter) => EnvFilter::try_new(filter)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+        Err(_) => EnvFilter::new(level(verbosity)),
+    };
+
+    let logs = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_filter(filter);
+
+    let (flame, guard) = match flame_file() {
+        Some(path) => {
+            let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+            let layer = FlameLayer::new(BufWriter::new(Prefixed::new(file, root)))
+                .with_threads_collapsed(true)
+                .with_file_and_line(false);
+            let guard = layer.flush_on_drop();
+
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    // fails only when a subscriber is already installed, which is fine
+    let _ = tracing_subscriber::registry()
+        .with(logs)
+        .with(flame)
+        .try_init();
+
+    Ok(TraceGuard(guard))
+}
+
+/// Whether spans are compiled in, which they are not in release builds
+pub fn enabled() -> bool {
+    STATIC_MAX_LEVEL != LevelFilter::OFF
+}
+
+fn flame_file() -> Option<PathBuf> {
+    env::var_os(FLAME_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Number of `-v` or `--verbose` flags among the arguments of the process
+pub fn verbosity_from_args() -> u8 {
+    env::args()
+        .skip(1)
+        .map(|arg| match arg.strip_prefix('-') {
+            Some("-verbose") => 1,
+            // short flags can be repeated, as in `-vv`
+            Some(flags) if flags.chars().all(|c| c == 'v') => flags.len() as u8,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Empties the folded stacks file, before processes append to it
+pub fn reset_flame_file(path: &Path) -> io::Result<()> {
+    File::create(path).map(|_| ())
+}
+
+/// Writer starting every line with a frame, the stacks of each process getting their own root
+pub struct Prefixed<W> {
+    inner: W,
+    prefix: String,
+    at_line_start: bool,
+}
+
+impl<W: Write> Prefixed<W> {
+    pub fn new(inner: W, root: &str) -> Self {
+        Self {
+            inner,
+            prefix: format!("{root};"),
+            at_line_start: true,
+        }
+    }
+}
+
+impl<W: Write> Write for Prefixed<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for line in buf.split_inclusive(|b| *b == b'\n') {
+            if self.at_line_start {
+                self.inner.write_all(self.prefix.as_bytes())?;
+            }
+
+            self.inner.write_all(line)?;
+            self.at_line_start = line.ends_with(b"\n");
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::trace::{level, Prefixed};
+
+    #[test]
+    fn test_prefixed() {
+        let mut out = Prefixed::new(vec![], "2023/d08");
+
+        write!(out, "part1 12\npart2;").unwrap();
+        out.write_all(b"crt 3\n").unwrap();
+
+        assert_eq!(
+            String::from_utf8(out.inner).unwrap(),
+            "2023/d08;part1 12\n2023/d08;part2;crt 3\n"
+        );
+    }
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), "warn");
+        assert_eq!(level(2), "debug");
+        assert_eq!(level(9), "trace");
+    }
+}
@@ -0,0 +1,129 @@
+//! Python module `aoc` exposing the solvers of the workspace:
+//!
+//! ```python
+//! import aoc
+//!
+//! aoc.solve(9, 1, text)           # any implemented day, of the latest year by default
+//! aoc.d09.part_1(text)            # the parts of each day of the latest year
+//! aoc.d09.extrapolate([0, 3, 6])  # and the reusable pieces of some days
+//! ```
+//!
+//! Solvers panicking on an invalid input raise `ValueError`, parts not implemented raise
+//! `NotImplementedError`. The parameters of the days are those of the workspace configuration.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use d09::Pyramid;
+use helpers::{config::Config, Day};
+use pyo3::{
+    exceptions::{PyNotImplementedError, PyValueError},
+    prelude::*,
+};
+use runner::{find_day, latest_year, panic_message, DAYS};
+
+/// Part of a day, called with the input to get the answer
+#[pyclass(frozen, module = "aoc")]
+struct Part {
+    day: &'static Day,
+    part: u8,
+}
+
+#[pymethods]
+impl Part {
+    fn __call__(&self, py: Python<'_>, input: &str) -> PyResult<String> {
+        solve_day(py, self.day, self.part, input)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<aoc {} part {}>", self.day.id(), self.part)
+    }
+}
+
+/// Solves a part of a day, of the latest year if none is given
+#[pyfunction]
+#[pyo3(signature = (day, part, input, year = None))]
+fn solve(py: Python<'_>, day: u8, part: u8, input: &str, year: Option<u16>) -> PyResult<String> {
+    let year = year.unwrap_or_else(latest_year);
+
+    let day = find_day(year, day).ok_or_else(|| {
+        PyNotImplementedError::new_err(format!("day {day} of {year} is not implemented"))
+    })?;
+
+    solve_day(py, day, part, input)
+}
+
+/// Implemented days, as `(year, day, title)` tuples
+#[pyfunction]
+fn days() -> Vec<(u16, u8, &'static str)> {
+    DAYS.iter().map(|d| (d.year, d.day, d.title)).collect()
+}
+
+/// Next value of a history of the report of day 9
+#[pyfunction]
+fn extrapolate(py: Python<'_>, values: Vec<i64>) -> PyResult<i64> {
+    guarded(py, || Pyramid::from_values(values).extrapolate())
+}
+
+/// Previous value of a history of the report of day 9
+#[pyfunction]
+fn extrapolate_backwards(py: Python<'_>, values: Vec<i64>) -> PyResult<i64> {
+    guarded(py, || Pyramid::from_values(values).extrapolate_backwards())
+}
+
+/// Sum of the distances between the galaxies of the image of day 11, each empty row and column
+/// being replaced by `expansion` of them
+#[pyfunction]
+#[pyo3(signature = (image, expansion = 2))]
+fn galaxy_distances(py: Python<'_>, image: &str, expansion: usize) -> PyResult<usize> {
+    guarded(py, || d11::galaxy_distances(image, expansion))
+}
+
+fn solve_day(py: Python<'_>, day: &Day, part: u8, input: &str) -> PyResult<String> {
+    let config =
+        Config::load().map_err(|e| PyValueError::new_err(format!("invalid configuration: {e}")))?;
+
+    guarded(py, || day.solve(part, input, &config))?.ok_or_else(|| {
+        PyNotImplementedError::new_err(format!("part {part} of {} is not implemented", day.id()))
+    })
+}
+
+/// Runs a solver without holding the GIL, turning its panics into `ValueError`
+fn guarded<T: Send>(py: Python<'_>, f: impl FnOnce() -> T + Send) -> PyResult<T> {
+    py.allow_threads(|| panic::catch_unwind(AssertUnwindSafe(f)))
+        .map_err(|payload| PyValueError::new_err(panic_message(payload.as_ref())))
+}
+
+#[pymodule]
+fn aoc(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+
+    m.add_class::<Part>()?;
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+
+    // submodules are registered in `sys.modules` for `from aoc.d09 import part_1` to work
+    let modules = py.import("sys")?.getattr("modules")?;
+
+    for day in DAYS.iter().filter(|d| d.year == latest_year()) {
+        let name = day.name();
+        let module = PyModule::new(py, &name)?;
+
+        module.add("title", day.title)?;
+        module.add("part_1", Part { day, part: 1 })?;
+        module.add("part_2", Part { day, part: 2 })?;
+
+        match day.day {
+            9 => {
+                module.add_function(wrap_pyfunction!(extrapolate, &module)?)?;
+                module.add_function(wrap_pyfunction!(extrapolate_backwards, &module)?)?;
+            }
+            11 => module.add_function(wrap_pyfunction!(galaxy_distances, &module)?)?,
+            _ => {}
+        }
+
+        m.add_submodule(&module)?;
+        modules.set_item(format!("aoc.{name}"), module)?;
+    }
+
+    Ok(())
+}
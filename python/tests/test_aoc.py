@@ -0,0 +1,73 @@
+import textwrap
+import unittest
+
+import aoc
+from aoc.d09 import extrapolate, extrapolate_backwards
+from aoc.d11 import galaxy_distances
+
+REPORT = textwrap.dedent("""\
+    0 3 6 9 12 15
+    1 3 6 10 15 21
+    10 13 16 21 30 45
+    """)
+
+IMAGE = textwrap.dedent("""\
+    ...#......
+    .......#..
+    #.........
+    ..........
+    ......#...
+    .#........
+    .........#
+    ..........
+    .......#..
+    #...#.....
+    """)
+
+
+class TestSolve(unittest.TestCase):
+    def test_parts(self):
+        self.assertEqual(aoc.d09.part_1(REPORT), "114")
+        self.assertEqual(aoc.d09.part_2(REPORT), "2")
+        self.assertEqual(aoc.d11.part_1(IMAGE), "374")
+        self.assertEqual(aoc.d09.title, "Mirage Maintenance")
+        self.assertEqual(repr(aoc.d09.part_1), "<aoc 2023/d09 part 1>")
+
+    def test_solve(self):
+        self.assertEqual(aoc.solve(9, 1, REPORT), "114")
+        self.assertEqual(aoc.solve(9, 2, REPORT, year=2023), "2")
+
+    def test_days(self):
+        days = aoc.days()
+
+        self.assertIn((2023, 9, "Mirage Maintenance"), days)
+        self.assertEqual(len(days), len(set(days)))
+
+    def test_errors(self):
+        with self.assertRaises(NotImplementedError):
+            aoc.solve(25, 1, REPORT)
+
+        with self.assertRaises(NotImplementedError):
+            aoc.solve(9, 3, REPORT)
+
+        # the solvers panic on invalid inputs
+        with self.assertRaises(ValueError):
+            aoc.d11.part_1("")
+
+
+class TestPieces(unittest.TestCase):
+    def test_extrapolate(self):
+        self.assertEqual(extrapolate([10, 13, 16, 21, 30, 45]), 68)
+        self.assertEqual(extrapolate_backwards([10, 13, 16, 21, 30, 45]), 5)
+
+        with self.assertRaises(ValueError):
+            extrapolate([])
+
+    def test_galaxy_distances(self):
+        self.assertEqual(galaxy_distances(IMAGE), 374)
+        self.assertEqual(galaxy_distances(IMAGE, expansion=10), 1030)
+        self.assertEqual(galaxy_distances(IMAGE, 100), 8410)
+
+
+if __name__ == "__main__":
+    unittest.main()
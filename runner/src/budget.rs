@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use helpers::config::Config;
+
+/// Resources a single part may use before its process is stopped
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Budget {
+    pub time: Duration,
+    /// Maximum address space of the process in bytes, unlimited if `None`
+    pub memory: Option<u64>,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            time: Duration::from_secs(10),
+            memory: Some(1024 * 1024 * 1024),
+        }
+    }
+}
+
+impl Budget {
+    /// Budget given on the command line, in seconds and MiB, else in the configuration, else the
+    /// default one. A memory of 0 disables the limit.
+    pub fn resolve(timeout: Option<f64>, memory: Option<u64>, config: &Config) -> Self {
+        let default = Budget::default();
+
+        let time = timeout
+            .or(config.timeout)
+            .map_or(default.time, Duration::from_secs_f64);
+
+        let memory = match memory.or(config.memory) {
+            Some(memory) => (memory > 0).then_some(memory * 1024 * 1024),
+            None => default.memory,
+        };
+
+        Budget { time, memory }
+    }
+}
+
+/// Caps the address space of the current process, so that allocations past the limit fail
+#[cfg(unix)]
+pub fn limit_memory(bytes: u64) -> std::io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+
+    // SAFETY: `limit` is a valid rlimit living for the duration of the call
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &limit) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Memory limits are only enforced on unix platforms
+#[cfg(not(unix))]
+pub fn limit_memory(_bytes: u64) -> std::io::Result<()> {
+    Ok(())
+}
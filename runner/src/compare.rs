@@ -0,0 +1,257 @@
+//! Runs the days on every named input of a directory, such as the inputs of each teammate
+//! (`alice.txt`, `bob.txt`), checking the answers recorded next to them (`alice.answers`).
+
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use helpers::{crypto::ENCRYPTED_EXTENSION, Day};
+use serde_json::Value;
+
+use crate::{
+    budget::Budget,
+    isolate::Outcome,
+    readme::{run_day, PartResult},
+};
+
+/// Results of both parts of a day on one input
+#[derive(Debug)]
+pub struct InputResult {
+    pub name: String,
+    pub parts: [PartResult; 2],
+}
+
+impl InputResult {
+    /// Whether a part failed or gave an answer differing from the accepted one
+    pub fn is_failure(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|p| p.outcome.is_failure() || p.verified == Some(false))
+    }
+
+    /// Results of the parts the day implements, with their number
+    fn implemented_parts(&self) -> impl Iterator<Item = (u8, &PartResult)> {
+        (1..)
+            .zip(&self.parts)
+            .filter(|(_, res)| res.outcome != Outcome::Unimplemented)
+    }
+}
+
+/// Inputs of a directory by name, sorted: `alice.txt` is named `alice`, and so is `alice.txt.enc`
+/// when only stored encrypted
+pub fn named_inputs(dir: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    let encrypted_suffix = format!(".txt.{ENCRYPTED_EXTENSION}");
+
+    let mut inputs = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        let name = file_name
+            .strip_suffix(&encrypted_suffix)
+            .or_else(|| file_name.strip_suffix(".txt"));
+
+        if let Some(name) = name {
+            // inputs are read through their plaintext path, decrypted when only stored encrypted
+            inputs.push((name.to_owned(), dir.join(format!("{name}.txt"))));
+        }
+    }
+
+    inputs.sort();
+    inputs.dedup();
+
+    Ok(inputs)
+}
+
+/// Runs both parts of the day on every named input of the directory
+pub fn compare_day(day: &Day, dir: &Path, budget: &Budget) -> io::Result<Vec<InputResult>> {
+    let inputs = named_inputs(dir)
+        .map_err(|e| io::Error::new(e.kind(), format!("cannot list {}: {e}", dir.display())))?;
+
+    inputs
+        .into_iter()
+        .map(|(name, path)| {
+            let parts = run_day(day, &path, budget)?;
+
+            Ok(InputResult { name, parts })
+        })
+        .collect()
+}
+
+/// One line per input and part, aligned on the names of the inputs
+pub fn render(day: &Day, results: &[InputResult]) -> String {
+    let width = results.iter().map(|r| r.name.len()).max().unwrap_or(0);
+
+    let mut report = String::new();
+
+    for result in results {
+        for (part, res) in result.implemented_parts() {
+            let verdict = match (res.verified, res.outcome.is_failure()) {
+                (_, true) => "FAIL",
+                (Some(true), _) => "pass",
+                (Some(false), _) => "WRONG",
+                (None, _) => "?",
+            };
+
+            let _ = writeln!(
+                report,
+                "{} {:width$} part {part}: {verdict:5} {}",
+                day.id(),
+                result.name,
+                res.outcome
+            );
+        }
+    }
+
+    report
+}
+
+/// One JSON object per input and part
+pub fn to_json(day: &Day, results: &[InputResult]) -> Vec<Value> {
+    let mut lines = vec![];
+
+    for result in results {
+        for (part, res) in result.implemented_parts() {
+            let mut line = res.outcome.to_json();
+
+            line["day"] = day.id().into();
+            line["input"] = result.name.clone().into();
+            line["part"] = part.into();
+            line["verified"] = res.verified.into();
+
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use helpers::Day;
+
+    use crate::{
+        compare::{named_inputs, render, to_json, InputResult},
+        isolate::Outcome,
+        readme::PartResult,
+    };
+
+    const DAY: Day = Day {
+        year: 2023,
+        day: 10,
+        title: "Pipe Maze",
+        inputs_dir: "d10/inputs",
+        params: &[],
+        part_1: None,
+        part_2: None,
+    };
+
+    fn solved(answer: &str, verified: Option<bool>) -> PartResult {
+        PartResult {
+            outcome: Outcome::Solved {
+                answer: answer.to_owned(),
+                elapsed: Duration::from_millis(2),
+                allocations: None,
+            },
+            verified,
+        }
+    }
+
+    #[test]
+    fn test_named_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-compare-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for file in [
+            "bob.txt",
+            "alice.txt.enc",
+            "alice.txt",
+            "alice.answers",
+            "notes.md",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        fs::write(dir.join("carol.txt.enc"), "").unwrap();
+
+        let names: Vec<(String, String)> = named_inputs(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|(name, path)| (name, path.file_name().unwrap().to_string_lossy().into()))
+            .collect();
+
+        // carol's input is read through its plaintext path, to be decrypted
+        let expected: Vec<(String, String)> = ["alice", "bob", "carol"]
+            .iter()
+            .map(|name| (name.to_string(), format!("{name}.txt")))
+            .collect();
+
+        assert_eq!(names, expected);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render() {
+        let results = [
+            InputResult {
+                name: "alice".to_owned(),
+                parts: [solved("6697", Some(true)), solved("423", None)],
+            },
+            InputResult {
+                name: "bob".to_owned(),
+                parts: [
+                    solved("12", Some(false)),
+                    PartResult {
+                        outcome: Outcome::Timeout(Duration::from_secs(1)),
+                        verified: None,
+                    },
+                ],
+            },
+        ];
+
+        assert!(!results[0].is_failure());
+        assert!(results[1].is_failure());
+
+        assert_eq!(
+            render(&DAY, &results),
+            "2023/d10 alice part 1: pass  6697 (2.00ms)\n\
+             2023/d10 alice part 2: ?     423 (2.00ms)\n\
+             2023/d10 bob   part 1: WRONG 12 (2.00ms)\n\
+             2023/d10 bob   part 2: FAIL  timeout after 1s\n"
+        );
+    }
+
+    #[test]
+    fn test_one_part() {
+        let results = [InputResult {
+            name: "alice".to_owned(),
+            parts: [
+                solved("6697", Some(true)),
+                PartResult {
+                    outcome: Outcome::Unimplemented,
+                    verified: None,
+                },
+            ],
+        }];
+
+        assert_eq!(
+            render(&DAY, &results),
+            "2023/d10 alice part 1: pass  6697 (2.00ms)\n"
+        );
+
+        let lines = to_json(&DAY, &results);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["part"], 1);
+        assert_eq!(lines[0]["input"], "alice");
+        assert_eq!(lines[0]["verified"], true);
+    }
+}
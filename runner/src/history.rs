@@ -0,0 +1,297 @@
+//! Timings of the benchmark runs, appended to a local history with the commit and the machine
+//! they were measured on, to chart them over commits and catch regressions.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write as _,
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::workspace_dir;
+
+/// History file, relative to the workspace
+pub const HISTORY_FILE: &str = ".perf-history.jsonl";
+/// Environment variable naming the machine, when its id is not the right grouping
+pub const MACHINE_ENV: &str = "AOC_MACHINE";
+
+/// Width of the longest bar of the charts
+const CHART_WIDTH: usize = 40;
+
+/// Best time of one part in one benchmark run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub commit: String,
+    pub machine: String,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Id of the day, e.g. `2023/d08`
+    pub day: String,
+    pub part: u8,
+    pub elapsed_nanos: u64,
+}
+
+impl Record {
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos)
+    }
+}
+
+/// Part slower than its previous best on the same machine
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: String,
+    pub part: u8,
+    pub best: Duration,
+    pub best_commit: String,
+    pub elapsed: Duration,
+}
+
+impl Regression {
+    /// How much slower than the best, in percent
+    pub fn slowdown(&self) -> f64 {
+        (self.elapsed.as_secs_f64() / self.best.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+pub fn load(path: &Path) -> io::Result<Vec<Record>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, format!("line {}: {e}", i + 1)))
+        })
+        .collect()
+}
+
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+    }
+
+    Ok(())
+}
+
+/// Short hash of the checked out commit, suffixed with `-dirty` when the tree has changes
+pub fn current_commit() -> io::Result<String> {
+    let git = |args: &[&str]| -> io::Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(workspace_dir())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+
+    Ok(if dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+/// `AOC_MACHINE` if set, else the id of the machine, else its host name
+pub fn machine_id() -> String {
+    if let Ok(machine) = env::var(MACHINE_ENV) {
+        return machine;
+    }
+
+    [
+        "/etc/machine-id",
+        "/var/lib/dbus/machine-id",
+        "/etc/hostname",
+    ]
+    .iter()
+    .filter_map(|path| fs::read_to_string(path).ok())
+    .map(|id| id.trim().to_owned())
+    .find(|id| !id.is_empty())
+    .or_else(|| env::var("HOSTNAME").ok())
+    .unwrap_or_else(|| "unknown".to_owned())
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Parts of the run slower than `threshold` percent over their best time in the history, on
+/// the same machine
+pub fn regressions(history: &[Record], run: &[Record], threshold: f64) -> Vec<Regression> {
+    run.iter()
+        .filter_map(|record| {
+            let best = history
+                .iter()
+                .filter(|r| {
+                    r.machine == record.machine && r.day == record.day && r.part == record.part
+                })
+                .min_by_key(|r| r.elapsed_nanos)?;
+
+            let regression = Regression {
+                day: record.day.clone(),
+                part: record.part,
+                best: best.elapsed(),
+                best_commit: best.commit.clone(),
+                elapsed: record.elapsed(),
+            };
+
+            (regression.slowdown() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+/// Chart of the best time of each part at each commit measured on the machine, commits in the
+/// order they were first measured
+pub fn report(history: &[Record], machine: &str) -> String {
+    let mut parts: BTreeMap<(&str, u8), Vec<(&str, Duration)>> = BTreeMap::new();
+
+    for record in history.iter().filter(|r| r.machine == machine) {
+        let timings = parts.entry((record.day.as_str(), record.part)).or_default();
+
+        match timings
+            .iter_mut()
+            .find(|(commit, _)| *commit == record.commit)
+        {
+            Some((_, best)) => *best = (*best).min(record.elapsed()),
+            None => timings.push((&record.commit, record.elapsed())),
+        }
+    }
+
+    let mut report = String::new();
+
+    for ((day, part), timings) in parts {
+        let _ = writeln!(report, "{day} part {part}");
+
+        let slowest = timings.iter().map(|(_, t)| *t).max().unwrap_or_default();
+        let width = timings.iter().map(|(c, _)| c.len()).max().unwrap_or(0);
+
+        for (commit, elapsed) in timings {
+            let ratio = elapsed.as_secs_f64() / slowest.as_secs_f64().max(f64::MIN_POSITIVE);
+            let bar = (ratio * CHART_WIDTH as f64).ceil() as usize;
+
+            let _ = writeln!(
+                report,
+                "  {commit:width$} {:CHART_WIDTH$} {elapsed:.2?}",
+                "█".repeat(bar)
+            );
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::history::{load, regressions, report, Record};
+
+    fn record(commit: &str, machine: &str, part: u8, millis: u64) -> Record {
+        Record {
+            commit: commit.to_owned(),
+            machine: machine.to_owned(),
+            timestamp: 1702000000,
+            day: "2023/d12".to_owned(),
+            part,
+            elapsed_nanos: millis * 1_000_000,
+        }
+    }
+
+    #[test]
+    fn test_regressions() {
+        let history = [
+            record("a1", "laptop", 2, 60),
+            record("b2", "laptop", 2, 40),
+            record("b2", "desktop", 2, 10),
+            record("b2", "laptop", 1, 4),
+        ];
+
+        let run = [
+            record("c3", "laptop", 1, 4),
+            record("c3", "laptop", 2, 50),
+            record("c3", "server", 2, 500),
+        ];
+
+        assert_eq!(
+            regressions(&history, &run, 10.0),
+            vec![crate::history::Regression {
+                day: "2023/d12".to_owned(),
+                part: 2,
+                best: Duration::from_millis(40),
+                best_commit: "b2".to_owned(),
+                elapsed: Duration::from_millis(50),
+            }]
+        );
+        assert_eq!(regressions(&history, &run, 30.0), vec![]);
+    }
+
+    #[test]
+    fn test_report() {
+        let history = [
+            record("a1", "laptop", 2, 80),
+            record("b2", "laptop", 2, 40),
+            record("b2", "laptop", 2, 20),
+            record("b2", "desktop", 2, 10),
+        ];
+
+        let bars = |n| format!("{:40}", "█".repeat(n));
+
+        assert_eq!(
+            report(&history, "laptop"),
+            format!(
+                "2023/d12 part 2\n  a1 {} 80.00ms\n  b2 {} 20.00ms\n",
+                bars(40),
+                bars(10)
+            )
+        );
+        assert_eq!(report(&history, "server"), "");
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.jsonl");
+
+        assert_eq!(load(&path).unwrap(), vec![]);
+
+        let records = [record("a1", "laptop", 1, 3), record("a1", "laptop", 2, 9)];
+        crate::history::append(&path, &records).unwrap();
+        crate::history::append(&path, &records[..1]).unwrap();
+
+        assert_eq!(load(&path).unwrap().len(), 3);
+
+        std::fs::write(&path, "{}\n").unwrap();
+        assert!(load(&path).unwrap_err().to_string().starts_with("line 1"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,317 @@
+use std::{
+    env, fmt,
+    io::{self, Read},
+    path::Path,
+    process::{self, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use helpers::{
+    alloc::{self, AllocStats},
+    config::Config,
+    phase, read_input, trace, Day,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{budget, budget::Budget, find_day};
+
+/// First argument marking a process spawned by [`run_part`] to solve a single part
+pub const WORKER_COMMAND: &str = "__worker";
+
+/// How often the parent checks whether the worker has exited
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// What happened when running one part of a day
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Solved {
+        answer: String,
+        elapsed: Duration,
+        /// Only counted when built with the `alloc-stats` feature
+        allocations: Option<Allocations>,
+    },
+    Unimplemented,
+    Timeout(Duration),
+    OutOfMemory(Option<u64>),
+    Failed(String),
+}
+
+impl Outcome {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Outcome::Solved { .. })
+    }
+
+    /// Whether the part is implemented but did not produce an answer
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Solved { .. } | Outcome::Unimplemented)
+    }
+
+    /// Machine readable form, for the JSON output of the runner
+    pub fn to_json(&self) -> Value {
+        match self {
+            Outcome::Solved {
+                answer,
+                elapsed,
+                allocations,
+            } => json!({
+                "status": "solved",
+                "answer": answer,
+                "elapsed_nanos": elapsed.as_nanos() as u64,
+                "allocations": allocations,
+            }),
+            Outcome::Unimplemented => json!({ "status": "unimplemented" }),
+            Outcome::Timeout(limit) => json!({
+                "status": "timeout",
+                "limit_nanos": limit.as_nanos() as u64,
+            }),
+            Outcome::OutOfMemory(limit) => json!({ "status": "out_of_memory", "limit": limit }),
+            Outcome::Failed(reason) => json!({ "status": "failed", "reason": reason }),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Solved {
+                answer, elapsed, ..
+            } => write!(f, "{answer} ({elapsed:.2?})"),
+            Outcome::Unimplemented => write!(f, "not implemented"),
+            Outcome::Timeout(limit) => write!(f, "timeout after {limit:?}"),
+            Outcome::OutOfMemory(Some(limit)) => {
+                write!(f, "out of memory (limit {} MiB)", limit / 1024 / 1024)
+            }
+            Outcome::OutOfMemory(None) => write!(f, "out of memory"),
+            Outcome::Failed(reason) => write!(f, "failed: {reason}"),
+        }
+    }
+}
+
+/// Allocations made by the worker while reading the input and while solving the part
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Allocations {
+    pub read: AllocStats,
+    /// Parsing phase of the part, `None` if the solver has none
+    pub parse: Option<AllocStats>,
+    /// Whole part, parsing included
+    pub part: AllocStats,
+    /// Highest resident set size of the worker, where the system tells
+    pub peak_resident_bytes: Option<u64>,
+}
+
+/// What the worker sends back to its parent on stdout
+#[derive(Debug, Serialize, Deserialize)]
+struct WorkerReport {
+    answer: String,
+    elapsed_nanos: u64,
+    allocations: Option<Allocations>,
+}
+
+/// Solves one part in a child process of the current executable, killing it once the
+/// budget is exceeded.
+///
+/// The executable must call [`intercept_worker`] at the start of its `main`.
+pub fn run_part(day: &Day, part: u8, input: &Path, budget: &Budget) -> io::Result<Outcome> {
+    run_part_with(&env::current_exe()?, day, part, input, budget)
+}
+
+/// Like [`run_part`], in a child process of another executable calling [`intercept_worker`],
+/// such as the runner for tests
+pub fn run_part_with(
+    worker: &Path,
+    day: &Day,
+    part: u8,
+    input: &Path,
+    budget: &Budget,
+) -> io::Result<Outcome> {
+    if day.part(part).is_none() {
+        return Ok(Outcome::Unimplemented);
+    }
+
+    let mut command = Command::new(worker);
+
+    command
+        .arg(WORKER_COMMAND)
+        .arg(day.year.to_string())
+        .arg(day.day.to_string())
+        .arg(part.to_string())
+        .arg(input)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if let Some(memory) = budget.memory {
+        command.arg(memory.to_string());
+    }
+
+    let mut child = command.spawn()?;
+
+    // drain the pipes concurrently so that a chatty worker never blocks on a full pipe
+    let stdout = drain(child.stdout.take().unwrap());
+    let stderr = drain(child.stderr.take().unwrap());
+
+    let deadline = Instant::now() + budget.time;
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let (stdout, stderr) = (stdout.join().unwrap(), stderr.join().unwrap());
+
+    // pass the logs on when they were asked for
+    if env::var_os(trace::LEVEL_ENV).is_some() {
+        eprint!("{stderr}");
+    }
+
+    Ok(match status {
+        None => Outcome::Timeout(budget.time),
+        Some(status) if status.success() => match serde_json::from_str::<WorkerReport>(&stdout) {
+            Ok(report) => Outcome::Solved {
+                answer: report.answer,
+                elapsed: Duration::from_nanos(report.elapsed_nanos),
+                allocations: report.allocations,
+            },
+            Err(e) => Outcome::Failed(format!("invalid worker report: {e}")),
+        },
+        Some(status) => classify_failure(&stderr, budget.memory).unwrap_or_else(|| {
+            Outcome::Failed(match stderr.lines().last() {
+                Some(reason) => reason.to_owned(),
+                None => format!("worker exited with {status}"),
+            })
+        }),
+    })
+}
+
+fn drain<R: Read + Send + 'static>(mut pipe: R) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        let _ = pipe.read_to_string(&mut output);
+        output
+    })
+}
+
+/// Tells an allocation failure from a panic by looking at what the worker printed
+fn classify_failure(stderr: &str, memory: Option<u64>) -> Option<Outcome> {
+    if stderr.contains("memory allocation of") {
+        return Some(Outcome::OutOfMemory(memory));
+    }
+
+    // the panic message is printed on the line after `thread '...' panicked at file:line:col:`
+    let mut lines = stderr.lines();
+    lines.find(|line| line.contains("panicked at"))?;
+
+    lines
+        .next()
+        .map(|reason| Outcome::Failed(reason.to_owned()))
+}
+
+/// Runs the worker and exits if the process was spawned by [`run_part`], does nothing otherwise
+pub fn intercept_worker() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.first().map(String::as_str) != Some(WORKER_COMMAND) {
+        return;
+    }
+
+    if let Err(e) = run_worker(&args[1..]) {
+        eprintln!("{e}");
+        process::exit(2);
+    }
+
+    process::exit(0);
+}
+
+fn run_worker(args: &[String]) -> Result<(), String> {
+    let [year, day, part, input, memory @ ..] = args else {
+        return Err(format!(
+            "usage: {WORKER_COMMAND} <year> <day> <part> <input> [memory]"
+        ));
+    };
+
+    let year: u16 = year
+        .parse()
+        .map_err(|e| format!("invalid year {year}: {e}"))?;
+    let day: u8 = day.parse().map_err(|e| format!("invalid day {day}: {e}"))?;
+    let part: u8 = part
+        .parse()
+        .map_err(|e| format!("invalid part {part}: {e}"))?;
+
+    let day = find_day(year, day)
+        .filter(|d| d.part(part).is_some())
+        .ok_or_else(|| format!("day {day} of {year} part {part} is not implemented"))?;
+
+    let _guard = trace::init(0, &day.id()).map_err(|e| format!("cannot set up tracing: {e}"))?;
+
+    // the worker inherits the environment, so it reads the same configuration as its parent
+    let config = Config::load().map_err(|e| format!("invalid configuration: {e}"))?;
+
+    let (content, read) = alloc::measure(|| read_input(Path::new(input)));
+    let input = content.map_err(|e| format!("cannot read {input}: {e}"))?;
+
+    if let Some(memory) = memory.first() {
+        let memory = memory
+            .parse()
+            .map_err(|e| format!("invalid memory limit {memory}: {e}"))?;
+
+        budget::limit_memory(memory).map_err(|e| format!("cannot limit memory: {e}"))?;
+    }
+
+    let ((answer, elapsed_nanos), part) = alloc::measure(|| {
+        let start = Instant::now();
+        let answer = day.solve(part, &input, &config).unwrap();
+
+        (answer, start.elapsed().as_nanos() as u64)
+    });
+
+    let report = WorkerReport {
+        answer,
+        elapsed_nanos,
+        allocations: cfg!(feature = "alloc-stats").then(|| Allocations {
+            read,
+            parse: phase::take_parse_stats(),
+            part,
+            peak_resident_bytes: alloc::peak_resident_bytes(),
+        }),
+    };
+
+    println!("{}", serde_json::to_string(&report).unwrap());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::isolate::{classify_failure, Outcome};
+
+    #[test]
+    fn test_classify_failure() {
+        assert_eq!(
+            classify_failure("memory allocation of 4096 bytes failed\n", Some(1024)),
+            Some(Outcome::OutOfMemory(Some(1024)))
+        );
+
+        assert_eq!(
+            classify_failure(
+                "\nthread 'main' panicked at d08/src/lib.rs:74:56:\ncalled `Option::unwrap()` on a `None` value\nnote: run with `RUST_BACKTRACE=1`\n",
+                None
+            ),
+            Some(Outcome::Failed(
+                "called `Option::unwrap()` on a `None` value".to_owned()
+            ))
+        );
+
+        assert_eq!(classify_failure("Killed\n", None), None);
+    }
+}
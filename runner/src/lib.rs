@@ -0,0 +1,124 @@
+use std::{any::Any, path::Path};
+
+use helpers::Day;
+
+pub mod budget;
+pub mod compare;
+pub mod history;
+pub mod isolate;
+pub mod readme;
+
+/// Every day implemented in the workspace, by year then in puzzle order
+pub static DAYS: &[&Day] = &[
+    &d01::DAY,
+    &d02::DAY,
+    &d03::DAY,
+    &d04::DAY,
+    &d05::DAY,
+    &d06::DAY,
+    &d07::DAY,
+    &d08::DAY,
+    &d09::DAY,
+    &d10::DAY,
+    &d11::DAY,
+    &d12::DAY,
+];
+
+/// Root of the workspace, where the day crates live
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Years with at least one implemented day, oldest first
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|d| d.year).collect();
+    years.dedup();
+
+    years
+}
+
+/// Year addressed when none is given
+pub fn latest_year() -> u16 {
+    DAYS.iter().map(|d| d.year).max().unwrap()
+}
+
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.year == year && d.day == day)
+        .copied()
+}
+
+/// Message of a panic caught with `catch_unwind`, for the bindings to report it
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "the solver panicked".to_owned(),
+    }
+}
+
+/// Days matching the filters: a day without a year is looked up in `default_year`, else in the
+/// latest year
+pub fn select_days(
+    year: Option<u16>,
+    day: Option<u8>,
+    default_year: Option<u16>,
+) -> Result<Vec<&'static Day>, String> {
+    match (year, day) {
+        (year, Some(day)) => {
+            let year = year.or(default_year).unwrap_or_else(latest_year);
+
+            find_day(year, day)
+                .map(|d| vec![d])
+                .ok_or_else(|| format!("day {day} of {year} is not implemented"))
+        }
+        (Some(year), None) => {
+            let days: Vec<&Day> = DAYS.iter().filter(|d| d.year == year).copied().collect();
+
+            if days.is_empty() {
+                Err(format!("no day of {year} is implemented"))
+            } else {
+                Ok(days)
+            }
+        }
+        (None, None) => Ok(DAYS.to_vec()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::config::{Config, DEFAULT_CONFIG_FILE};
+
+    use crate::{find_day, latest_year, select_days, years, DAYS};
+
+    #[test]
+    fn test_select_days() {
+        assert_eq!(years(), vec![2023]);
+        assert_eq!(latest_year(), 2023);
+
+        assert_eq!(find_day(2023, 8).unwrap().title, "Haunted Wasteland");
+        assert!(find_day(2022, 8).is_none());
+
+        assert_eq!(select_days(None, None, None).unwrap().len(), 12);
+        assert_eq!(select_days(Some(2023), None, None).unwrap().len(), 12);
+        assert_eq!(
+            select_days(None, Some(8), None).unwrap()[0].id(),
+            "2023/d08"
+        );
+        assert!(select_days(None, Some(8), Some(2022)).is_err());
+        assert!(select_days(Some(2023), Some(8), Some(2022)).is_ok());
+        assert!(select_days(Some(2015), None, None).is_err());
+        assert!(select_days(Some(2023), Some(25), None).is_err());
+    }
+
+    #[test]
+    fn test_workspace_config() {
+        let content = std::fs::read_to_string(DEFAULT_CONFIG_FILE).unwrap();
+        let config = Config::from_toml(&content).unwrap();
+
+        config.check_days(DAYS).unwrap();
+    }
+}
@@ -0,0 +1,1009 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use helpers::{
+    alloc,
+    config::{Config, Format},
+    crypto::{self, InputKey},
+    leaderboard::{Leaderboard, Scoring},
+    puzzle,
+    share::AnswerHash,
+    snapshot, trace, Day,
+};
+use runner::{
+    budget::Budget,
+    compare, find_day,
+    history::{self, Record},
+    isolate::{intercept_worker, run_part, Outcome},
+    readme, select_days, workspace_dir, DAYS,
+};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: helpers::alloc::CountingAllocator = helpers::alloc::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solvers of the workspace")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Logs the spans of the solvers with their timings, repeat for more details. Spans are
+    /// only compiled in debug builds.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Appends the folded stacks of the spans to this file, for flamegraph tools
+    #[arg(long, global = true)]
+    flame: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves every part of the selected days, each in its own process
+    Run {
+        /// Day to run, all days if omitted
+        day: Option<u8>,
+
+        /// Year of the days to run, the default one of the configuration or else the latest one
+        /// when only a day is given, all if omitted
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Part to run, both parts if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Output format, `text` or `json`, the one of the configuration if omitted
+        #[arg(short, long)]
+        format: Option<Format>,
+
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Solves the selected days on every named input of their inputs directory, such as
+    /// `alice.txt` and `bob.txt`, checking the answers recorded next to them in `alice.answers`
+    Compare {
+        /// Day to run, all days if omitted
+        day: Option<u8>,
+
+        /// Year of the days to run, the default one of the configuration or else the latest one
+        /// when only a day is given, all if omitted
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Directory of the inputs, holding those of each day under its id, as in
+        /// `2023/d10/alice.txt`, the inputs directory of each day if omitted
+        #[arg(long)]
+        dir: Option<PathBuf>,
+
+        /// Output format, `text` or `json`, the one of the configuration if omitted
+        #[arg(short, long)]
+        format: Option<Format>,
+
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Times the selected parts, records their best times in the history, and fails if one is
+    /// slower than its previous best on this machine
+    Bench {
+        /// Day to run, all days if omitted
+        day: Option<u8>,
+
+        /// Year of the days to run, the default one of the configuration or else the latest one
+        /// when only a day is given, all if omitted
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Part to run, both parts if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Runs of each part, the best one being recorded
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+
+        /// Slowdown over the previous best failing the benchmark, in percent, the one of the
+        /// configuration or 10 if omitted
+        #[arg(long)]
+        threshold: Option<f64>,
+
+        #[command(flatten)]
+        history: HistoryArgs,
+
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Charts the recorded times of each part over the commits measured on a machine
+    History {
+        /// Machine whose times to chart, this one if omitted
+        #[arg(long)]
+        machine: Option<String>,
+
+        #[command(flatten)]
+        history: HistoryArgs,
+    },
+    /// Runs every day and rewrites the results table of the README
+    Readme {
+        /// README to update, the one at the root of the workspace if omitted
+        #[arg(long)]
+        path: Option<PathBuf>,
+
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Shows the difference of each snapshot left pending by the tests, and accepts or rejects it
+    Snapshots {
+        /// Accept every pending snapshot without asking
+        #[arg(long)]
+        accept: bool,
+    },
+    /// Shows the statement of a puzzle in Markdown, from its page saved next to the inputs, which
+    /// is downloaded when missing
+    Puzzle {
+        day: u8,
+
+        /// Year of the puzzle, the default one of the configuration or else the latest one if
+        /// omitted
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Downloads the page again, to get the second part once the first one is solved
+        #[arg(long)]
+        refresh: bool,
+
+        /// Column the paragraphs are wrapped at
+        #[arg(long, default_value_t = 100)]
+        width: usize,
+    },
+    /// Prints a salted hash of the answer to each part on the local input, for teammates to
+    /// check theirs with `verify` without either being revealed
+    Share {
+        /// Day to hash the answers of, all days if omitted
+        day: Option<u8>,
+
+        /// Year of the days, the default one of the configuration or else the latest one when
+        /// only a day is given, all if omitted
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Part to hash the answer of, both parts if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Tells whether hashes printed by `share` on another machine were made from the same input
+    /// and answer as the local ones
+    Verify {
+        #[arg(required = true)]
+        hashes: Vec<AnswerHash>,
+
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Generates the key encrypting the inputs, in the key file
+    Keygen,
+    /// Encrypts plaintext inputs next to themselves, as `<input>.enc`
+    Encrypt {
+        /// Inputs to encrypt, every day's `input.txt` and `puzzle.html` present in plaintext if
+        /// omitted
+        paths: Vec<PathBuf>,
+
+        /// Only encrypt the inputs of the days of that year when no path is given
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Keep the plaintext files instead of deleting them
+        #[arg(long)]
+        keep: bool,
+    },
+    /// Re-encrypts every encrypted input with a newly generated key
+    Rekey,
+    /// Reports statistics of a private leaderboard
+    Leaderboard {
+        /// Leaderboard JSON export saved locally
+        #[arg(long, conflicts_with = "url", required_unless_present = "url")]
+        file: Option<PathBuf>,
+
+        /// URL of the leaderboard JSON export, fetched with the session cookie in `AOC_SESSION`
+        /// or in the session file of the configuration
+        #[arg(long)]
+        url: Option<String>,
+
+        #[arg(long, value_enum, default_value_t = LeaderboardReport::Scores)]
+        report: LeaderboardReport,
+
+        /// Scoring rule of the scores report: `local`, `stars` or `top:<n>`
+        #[arg(long, default_value = "local")]
+        scoring: Scoring,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LeaderboardReport {
+    /// Solve time of every member for each part of each day
+    Rankings,
+    /// Time between the first and the second star of each day
+    Deltas,
+    /// Scores recomputed with the chosen rule
+    Scores,
+}
+
+#[derive(Args)]
+struct HistoryArgs {
+    /// History of the benchmarks, `.perf-history.jsonl` at the root of the workspace if omitted
+    #[arg(long = "history")]
+    path: Option<PathBuf>,
+}
+
+impl HistoryArgs {
+    fn path(self) -> PathBuf {
+        self.path
+            .unwrap_or_else(|| workspace_dir().join(history::HISTORY_FILE))
+    }
+}
+
+#[derive(Args)]
+struct BudgetArgs {
+    /// Wall-clock budget of each part, in seconds, the one of the configuration or 10 if omitted
+    #[arg(short, long)]
+    timeout: Option<f64>,
+
+    /// Memory budget of each part, in MiB, 0 to disable the limit, the one of the configuration
+    /// or 1024 if omitted
+    #[arg(short, long)]
+    memory: Option<u64>,
+}
+
+impl BudgetArgs {
+    /// Flags take precedence over the configuration, itself over the defaults
+    fn budget(&self, config: &Config) -> Budget {
+        Budget::resolve(self.timeout, self.memory, config)
+    }
+}
+
+fn main() -> ExitCode {
+    intercept_worker();
+
+    let cli = Cli::parse();
+
+    if let Err(e) = init_tracing(cli.verbose, cli.flame.as_deref()) {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+
+    let config = match Config::load().and_then(|config| config.check_days(DAYS).map(|_| config)) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("invalid configuration: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let res = match cli.command {
+        Command::Run {
+            day,
+            year,
+            part,
+            format,
+            budget,
+        } => {
+            let format = format.unwrap_or(config.format);
+
+            run(&config, year, day, part, format, &budget.budget(&config))
+        }
+        Command::Compare {
+            day,
+            year,
+            dir,
+            format,
+            budget,
+        } => {
+            let format = format.unwrap_or(config.format);
+            let days = select_days(year, day, config.year);
+
+            days.and_then(|days| {
+                compare_inputs(&config, &days, dir, format, &budget.budget(&config))
+            })
+        }
+        Command::Bench {
+            day,
+            year,
+            part,
+            runs,
+            threshold,
+            history,
+            budget,
+        } => {
+            let threshold = threshold.or(config.regression_threshold).unwrap_or(10.0);
+            let days = select_days(year, day, config.year);
+
+            days.and_then(|days| {
+                bench(
+                    &config,
+                    &days,
+                    part,
+                    runs,
+                    threshold,
+                    &history.path(),
+                    &budget.budget(&config),
+                )
+            })
+        }
+        Command::History { machine, history } => show_history(machine, &history.path()),
+        Command::Readme { path, budget } => update_readme(&config, path, &budget.budget(&config)),
+        Command::Snapshots { accept } => review_snapshots(accept),
+        Command::Puzzle {
+            day,
+            year,
+            refresh,
+            width,
+        } => select_days(year, Some(day), config.year)
+            .and_then(|days| show_puzzle(&config, days[0], refresh, width)),
+        Command::Share {
+            day,
+            year,
+            part,
+            budget,
+        } => select_days(year, day, config.year)
+            .and_then(|days| share(&config, &days, part, &budget.budget(&config))),
+        Command::Verify { hashes, budget } => verify(&config, &hashes, &budget.budget(&config)),
+        Command::Keygen => keygen(),
+        Command::Encrypt { paths, year, keep } => encrypt(&config, paths, year, keep),
+        Command::Rekey => rekey(&config),
+        Command::Leaderboard {
+            file,
+            url,
+            report,
+            scoring,
+        } => leaderboard(&config, file, url, report, scoring),
+    };
+
+    match res {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Hands the tracing options over to the workers, which inherit the environment
+fn init_tracing(verbose: u8, flame: Option<&Path>) -> Result<(), String> {
+    if (verbose > 0 || flame.is_some()) && !trace::enabled() {
+        eprintln!("spans are compiled out of release builds, use a debug build to trace them");
+    }
+
+    if verbose > 0 && env::var_os(trace::LEVEL_ENV).is_none() {
+        env::set_var(trace::LEVEL_ENV, trace::level(verbose));
+    }
+
+    if let Some(flame) = flame {
+        trace::reset_flame_file(flame)
+            .map_err(|e| format!("cannot create {}: {e}", flame.display()))?;
+
+        env::set_var(trace::FLAME_ENV, flame);
+    }
+
+    Ok(())
+}
+
+fn run(
+    config: &Config,
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    format: Format,
+    budget: &Budget,
+) -> Result<ExitCode, String> {
+    let days = select_days(year, day, config.year)?;
+
+    let mut any_failure = false;
+
+    for day in days {
+        for p in part.map_or(1..=2, |p| p..=p) {
+            let outcome = run_part(day, p, &config.input_path(day), budget)
+                .map_err(|e| format!("cannot run {} part {p}: {e}", day.id()))?;
+
+            any_failure |= outcome.is_failure();
+
+            if format == Format::Json {
+                let mut line = outcome.to_json();
+                line["day"] = day.id().into();
+                line["part"] = p.into();
+
+                println!("{line}");
+                continue;
+            }
+
+            println!("{} part {p}: {outcome}", day.id());
+
+            if let Outcome::Solved {
+                allocations: Some(allocations),
+                ..
+            } = outcome
+            {
+                println!("    read:  {}", allocations.read);
+
+                if let Some(parse) = allocations.parse {
+                    println!("    parse: {parse}");
+                }
+
+                println!("    part:  {}", allocations.part);
+
+                if let Some(peak) = allocations.peak_resident_bytes {
+                    println!("    peak resident: {}", alloc::format_bytes(peak));
+                }
+            }
+        }
+    }
+
+    Ok(if any_failure {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn compare_inputs(
+    config: &Config,
+    days: &[&Day],
+    dir: Option<PathBuf>,
+    format: Format,
+    budget: &Budget,
+) -> Result<ExitCode, String> {
+    let (mut passed, mut failed, mut unchecked) = (0, 0, 0);
+
+    for day in days {
+        let inputs_dir = match &dir {
+            Some(dir) => dir.join(day.id()),
+            None => config.inputs_dir(day),
+        };
+
+        // teammates may not have shared inputs for every day
+        if dir.is_some() && !inputs_dir.is_dir() {
+            continue;
+        }
+
+        let results = compare::compare_day(day, &inputs_dir, budget)
+            .map_err(|e| format!("cannot run {}: {e}", day.id()))?;
+
+        match format {
+            Format::Text => print!("{}", compare::render(day, &results)),
+            Format::Json => {
+                for line in compare::to_json(day, &results) {
+                    println!("{line}");
+                }
+            }
+        }
+
+        for result in &results {
+            if result.is_failure() {
+                failed += 1;
+            } else if result
+                .parts
+                .iter()
+                .any(|p| p.verified.is_none() && p.outcome.is_solved())
+            {
+                unchecked += 1;
+            } else {
+                passed += 1;
+            }
+        }
+    }
+
+    if format == Format::Text {
+        println!("{passed} inputs passed, {failed} failed, {unchecked} without recorded answers");
+    }
+
+    Ok(if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn bench(
+    config: &Config,
+    days: &[&Day],
+    part: Option<u8>,
+    runs: u32,
+    threshold: f64,
+    path: &Path,
+    budget: &Budget,
+) -> Result<ExitCode, String> {
+    let history =
+        history::load(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+
+    let commit = history::current_commit().map_err(|e| format!("cannot find the commit: {e}"))?;
+    let machine = history::machine_id();
+    let timestamp = history::now();
+
+    let mut records = vec![];
+    let mut any_failure = false;
+
+    for day in days {
+        for p in part.map_or(1..=2, |p| p..=p) {
+            let mut best = None;
+
+            for _ in 0..runs {
+                let outcome = run_part(day, p, &config.input_path(day), budget)
+                    .map_err(|e| format!("cannot run {} part {p}: {e}", day.id()))?;
+
+                match outcome {
+                    Outcome::Solved { elapsed, .. } => {
+                        best = Some(best.map_or(elapsed, |b: Duration| b.min(elapsed)))
+                    }
+                    Outcome::Unimplemented => break,
+                    outcome => {
+                        println!("{} part {p}: {outcome}", day.id());
+                        any_failure = true;
+                        best = None;
+                        break;
+                    }
+                }
+            }
+
+            let Some(best) = best else {
+                continue;
+            };
+
+            println!("{} part {p}: {best:.2?}", day.id());
+
+            records.push(Record {
+                commit: commit.clone(),
+                machine: machine.clone(),
+                timestamp,
+                day: day.id(),
+                part: p,
+                elapsed_nanos: best.as_nanos() as u64,
+            });
+        }
+    }
+
+    let regressions = history::regressions(&history, &records, threshold);
+
+    history::append(path, &records).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+
+    for regression in &regressions {
+        println!(
+            "{} part {} regressed by {:.1}%: {:.2?}, best {:.2?} at {}",
+            regression.day,
+            regression.part,
+            regression.slowdown(),
+            regression.elapsed,
+            regression.best,
+            regression.best_commit
+        );
+    }
+
+    Ok(if any_failure || !regressions.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn show_history(machine: Option<String>, path: &Path) -> Result<ExitCode, String> {
+    let history =
+        history::load(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    let machine = machine.unwrap_or_else(history::machine_id);
+
+    let report = history::report(&history, &machine);
+
+    if report.is_empty() {
+        println!("no benchmark recorded on {machine}");
+    }
+
+    print!("{report}");
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn update_readme(
+    config: &Config,
+    path: Option<PathBuf>,
+    budget: &Budget,
+) -> Result<ExitCode, String> {
+    let path = path.unwrap_or_else(|| workspace_dir().join("README.md"));
+
+    let mut results = vec![];
+
+    for &day in DAYS {
+        let parts = readme::run_day(day, &config.input_path(day), budget)
+            .map_err(|e| format!("cannot run {}: {e}", day.id()))?;
+
+        results.push((day, parts));
+    }
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+
+    let content = readme::replace_section(&content, &readme::render_table(&results))?;
+
+    fs::write(&path, content).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+
+    println!("updated {}", path.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn review_snapshots(accept_all: bool) -> Result<ExitCode, String> {
+    let mut pending = vec![];
+
+    for day in DAYS {
+        let dir = day.crate_dir();
+        pending.extend(
+            snapshot::pending(dir).map_err(|e| format!("cannot list {}: {e}", dir.display()))?,
+        );
+    }
+
+    if pending.is_empty() {
+        println!("no pending snapshot");
+    }
+
+    let mut answers = io::stdin().lock().lines();
+
+    for path in pending {
+        let accepted = snapshot::accepted_path(&path);
+
+        let read = |path: &Path| match fs::read_to_string(path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+            res => res.map_err(|e| format!("cannot read {}: {e}", path.display())),
+        };
+
+        println!("{}", accepted.display());
+        print!("{}", snapshot::diff(&read(&accepted)?, &read(&path)?));
+
+        let answer = if accept_all {
+            "y".to_owned()
+        } else {
+            print!("accept [y], reject [n] or skip [s]? ");
+            io::stdout().flush().map_err(|e| e.to_string())?;
+
+            match answers.next() {
+                Some(answer) => answer.map_err(|e| e.to_string())?,
+                None => break,
+            }
+        };
+
+        match answer.trim() {
+            "y" => snapshot::accept(&path)
+                .map_err(|e| format!("cannot accept {}: {e}", path.display()))?,
+            "n" => fs::remove_file(&path)
+                .map_err(|e| format!("cannot remove {}: {e}", path.display()))?,
+            _ => continue,
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn show_puzzle(
+    config: &Config,
+    day: &Day,
+    refresh: bool,
+    width: usize,
+) -> Result<ExitCode, String> {
+    let path = config.puzzle_path(day);
+
+    // saved pages are read like inputs, decrypted when only stored encrypted
+    let saved = match helpers::read_input(&path) {
+        Ok(html) => Some(html),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+    };
+
+    let html = match saved {
+        Some(html) if !refresh => html,
+        _ => {
+            // without a session, the page only has the first part
+            let session = config.session().ok();
+
+            let html = puzzle::fetch(day.year, day.day, session.as_deref())
+                .map_err(|e| format!("cannot fetch the puzzle of {}: {e}", day.id()))?;
+
+            fs::create_dir_all(path.parent().unwrap())
+                .and_then(|_| fs::write(&path, &html))
+                .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+
+            eprintln!("saved {}", path.display());
+
+            html
+        }
+    };
+
+    let markdown =
+        puzzle::to_markdown(&html, width).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    print!("{markdown}");
+
+    if puzzle::parts(&html) < 2 {
+        eprintln!("the saved page has no part 2, refresh it with --refresh once part 1 is solved");
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn share(
+    config: &Config,
+    days: &[&Day],
+    part: Option<u8>,
+    budget: &Budget,
+) -> Result<ExitCode, String> {
+    let mut any_failure = false;
+
+    for day in days {
+        for p in part.map_or(1..=2, |p| p..=p) {
+            match solve_locally(config, day, p, budget)? {
+                (input, Outcome::Solved { answer, .. }) => {
+                    println!("{}", AnswerHash::new(day, p, &input, &answer));
+                }
+                (_, Outcome::Unimplemented) => {}
+                (_, outcome) => {
+                    any_failure = true;
+                    eprintln!("{} part {p}: {outcome}", day.id());
+                }
+            }
+        }
+    }
+
+    Ok(if any_failure {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn verify(config: &Config, hashes: &[AnswerHash], budget: &Budget) -> Result<ExitCode, String> {
+    let mut all_match = true;
+
+    for hash in hashes {
+        let day = find_day(hash.year, hash.day)
+            .ok_or_else(|| format!("day {} of {} is not implemented", hash.day, hash.year))?;
+
+        let verdict = match solve_locally(config, day, hash.part, budget)? {
+            (input, Outcome::Solved { answer, .. }) if hash.matches(&input, &answer) => {
+                "same answer to the same input".to_owned()
+            }
+            (_, Outcome::Solved { .. }) => {
+                all_match = false;
+                "DIFFERENT answer or input".to_owned()
+            }
+            (_, outcome) => {
+                all_match = false;
+                outcome.to_string()
+            }
+        };
+
+        println!("{} part {}: {verdict}", day.id(), hash.part);
+    }
+
+    Ok(if all_match {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Outcome of a part on the local input, with the input it was solved on
+fn solve_locally(
+    config: &Config,
+    day: &Day,
+    part: u8,
+    budget: &Budget,
+) -> Result<(String, Outcome), String> {
+    let path = config.input_path(day);
+
+    let input =
+        helpers::read_input(&path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+
+    let outcome = run_part(day, part, &path, budget)
+        .map_err(|e| format!("cannot run {} part {part}: {e}", day.id()))?;
+
+    Ok((input, outcome))
+}
+
+fn keygen() -> Result<ExitCode, String> {
+    let path = crypto::key_file();
+
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+
+    write_key(&path, &InputKey::generate())?;
+
+    println!("generated {}", path.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn write_key(path: &Path, key: &InputKey) -> Result<(), String> {
+    fs::write(path, format!("{}\n", key.to_hex()))
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| format!("cannot restrict access to {}: {e}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+fn encrypt(
+    config: &Config,
+    paths: Vec<PathBuf>,
+    year: Option<u16>,
+    keep: bool,
+) -> Result<ExitCode, String> {
+    let key = InputKey::load().map_err(|e| e.to_string())?;
+
+    let paths = if paths.is_empty() {
+        select_days(year, None, None)?
+            .iter()
+            .flat_map(|day| [config.input_path(day), config.puzzle_path(day)])
+            .filter(|path| path.exists())
+            .collect()
+    } else {
+        paths
+    };
+
+    for path in paths {
+        let encrypted = crypto::encrypt_file(&key, &path)
+            .map_err(|e| format!("cannot encrypt {}: {e}", path.display()))?;
+
+        if !keep {
+            fs::remove_file(&path).map_err(|e| format!("cannot remove {}: {e}", path.display()))?;
+        }
+
+        println!("encrypted {}", encrypted.display());
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn rekey(config: &Config) -> Result<ExitCode, String> {
+    let old_key = InputKey::load().map_err(|e| e.to_string())?;
+    let new_key = InputKey::generate();
+
+    let mut encrypted = vec![];
+
+    for day in DAYS {
+        let dir = config.inputs_dir(day);
+        let entries =
+            fs::read_dir(&dir).map_err(|e| format!("cannot list {}: {e}", dir.display()))?;
+
+        for entry in entries {
+            let path = entry.map_err(|e| e.to_string())?.path();
+
+            if path
+                .extension()
+                .is_some_and(|e| e == crypto::ENCRYPTED_EXTENSION)
+            {
+                encrypted.push(path);
+            }
+        }
+    }
+
+    // re-encrypt everything aside first, so that a bad old key leaves the inputs untouched
+    let mut staged = vec![];
+
+    for path in &encrypted {
+        let tmp = path.with_extension("enc.tmp");
+
+        let res = fs::read(path)
+            .map_err(|e| format!("cannot read {}: {e}", path.display()))
+            .and_then(|data| {
+                old_key
+                    .decrypt(&data)
+                    .map_err(|e| format!("{}: {e}", path.display()))
+            })
+            .and_then(|plaintext| {
+                fs::write(&tmp, new_key.encrypt(&plaintext))
+                    .map_err(|e| format!("cannot write {}: {e}", tmp.display()))
+            });
+
+        staged.push((tmp, path));
+
+        if let Err(e) = res {
+            discard(&staged);
+            return Err(e);
+        }
+    }
+
+    if std::env::var_os(crypto::KEY_ENV).is_some() {
+        println!(
+            "new key, to set in {}: {}",
+            crypto::KEY_ENV,
+            new_key.to_hex()
+        );
+
+        return replace_staged(staged, &format!("the previous {}", crypto::KEY_ENV));
+    }
+
+    // the inputs not replaced yet if a rename fails still need the old key
+    let key_file = crypto::key_file();
+    let mut backup = key_file.clone().into_os_string();
+    backup.push(".old");
+    let backup = PathBuf::from(backup);
+
+    if let Err(e) = write_key(&backup, &old_key).and_then(|_| write_key(&key_file, &new_key)) {
+        discard(&staged);
+        return Err(e);
+    }
+
+    let code = replace_staged(staged, &backup.display().to_string())?;
+
+    fs::remove_file(&backup).map_err(|e| format!("cannot remove {}: {e}", backup.display()))?;
+
+    Ok(code)
+}
+
+/// Moves the re-encrypted inputs over the old ones, `old_key` telling where the old key is
+/// kept should one fail
+fn replace_staged(staged: Vec<(PathBuf, &PathBuf)>, old_key: &str) -> Result<ExitCode, String> {
+    for (i, (tmp, path)) in staged.iter().enumerate() {
+        if let Err(e) = fs::rename(tmp, path) {
+            discard(&staged[i..]);
+
+            return Err(format!(
+                "cannot replace {}: {e}, the inputs not re-encrypted yet still use the old key, \
+                 in {old_key}",
+                path.display()
+            ));
+        }
+
+        println!("re-encrypted {}", path.display());
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Removes the re-encrypted inputs left aside by a failed rekey
+fn discard(staged: &[(PathBuf, &PathBuf)]) {
+    for (tmp, _) in staged {
+        let _ = fs::remove_file(tmp);
+    }
+}
+
+fn leaderboard(
+    config: &Config,
+    file: Option<PathBuf>,
+    url: Option<String>,
+    report: LeaderboardReport,
+    scoring: Scoring,
+) -> Result<ExitCode, String> {
+    let leaderboard = match (file, url) {
+        (Some(file), _) => Leaderboard::from_file(&file)
+            .map_err(|e| format!("cannot read {}: {e}", file.display()))?,
+        (None, Some(url)) => {
+            let session = config
+                .session()
+                .map_err(|e| format!("no session cookie to fetch {url}: {e}"))?;
+
+            Leaderboard::fetch(&url, &session).map_err(|e| format!("cannot fetch {url}: {e}"))?
+        }
+        (None, None) => unreachable!("clap requires either a file or an url"),
+    };
+
+    let report = match report {
+        LeaderboardReport::Rankings => leaderboard.rankings_report(),
+        LeaderboardReport::Deltas => leaderboard.deltas_report(),
+        LeaderboardReport::Scores => leaderboard.scores_report(scoring),
+    };
+
+    print!("{report}");
+
+    Ok(ExitCode::SUCCESS)
+}
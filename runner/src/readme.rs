@@ -0,0 +1,215 @@
+use std::{fmt::Write, io, path::Path};
+
+use helpers::{known_answers, Day};
+
+use crate::{
+    budget::Budget,
+    isolate::{run_part, Outcome},
+    workspace_dir,
+};
+
+/// Marks the beginning of the generated section of the README
+pub const BEGIN_MARKER: &str = "<!-- results:begin -->";
+/// Marks the end of the generated section of the README
+pub const END_MARKER: &str = "<!-- results:end -->";
+
+/// Result of one part, checked against the accepted answer when it is known
+#[derive(Debug)]
+pub struct PartResult {
+    pub outcome: Outcome,
+    /// `None` when no accepted answer is recorded for the input
+    pub verified: Option<bool>,
+}
+
+impl PartResult {
+    /// A star is earned by any answer that does not contradict the accepted one
+    pub fn has_star(&self) -> bool {
+        self.outcome.is_solved() && self.verified != Some(false)
+    }
+}
+
+/// Runs both parts of a day on an input and checks them against the accepted answers
+pub fn run_day(day: &Day, input: &Path, budget: &Budget) -> io::Result<[PartResult; 2]> {
+    let [answer_1, answer_2] = known_answers(input)?;
+
+    let check = |part: u8, known: Option<String>| -> io::Result<PartResult> {
+        let outcome = run_part(day, part, input, budget)?;
+
+        let verified = match (&outcome, known) {
+            (Outcome::Solved { answer, .. }, Some(known)) => Some(*answer == known),
+            _ => None,
+        };
+
+        Ok(PartResult { outcome, verified })
+    };
+
+    Ok([check(1, answer_1)?, check(2, answer_2)?])
+}
+
+/// Renders the results as one markdown table per year, one row per day
+///
+/// The answers are only marked as verified when some accepted answer is recorded
+pub fn render_table(results: &[(&Day, [PartResult; 2])]) -> String {
+    let mut table = String::new();
+
+    let any_known = results
+        .iter()
+        .any(|(_, parts)| parts.iter().any(|p| p.verified.is_some()));
+
+    for (i, (day, parts)) in results.iter().enumerate() {
+        if i == 0 || results[i - 1].0.year != day.year {
+            if i > 0 {
+                table.push('\n');
+            }
+
+            let _ = writeln!(table, "### {}\n", day.year);
+            table.push_str("| Day | Puzzle | Stars | Part 1 | Part 2 |\n");
+            table.push_str("| --- | --- | --- | --- | --- |\n");
+        }
+
+        let stars = parts.iter().filter(|p| p.has_star()).count();
+
+        // link relatively to the README, at the root of the workspace
+        let dir = day.crate_dir();
+        let dir = dir.strip_prefix(workspace_dir()).unwrap_or(dir);
+
+        let _ = writeln!(
+            table,
+            "| [{}]({}/src/lib.rs) | [{}]({}) | {} | {} | {} |",
+            day.name(),
+            dir.display(),
+            day.title,
+            day.url(),
+            "⭐".repeat(stars),
+            render_part(&parts[0], any_known),
+            render_part(&parts[1], any_known),
+        );
+    }
+
+    if any_known {
+        table.push_str("\n✅ answer verified, ❌ answer differs from the accepted one, ❔ no accepted answer recorded\n");
+    }
+
+    table
+}
+
+fn render_part(part: &PartResult, any_known: bool) -> String {
+    let verified = match part.verified {
+        Some(true) => "✅ ",
+        Some(false) => "❌ ",
+        None if any_known => "❔ ",
+        None => "",
+    };
+
+    match &part.outcome {
+        Outcome::Solved { elapsed, .. } => format!("{verified}{elapsed:.2?}"),
+        Outcome::Unimplemented => String::new(),
+        Outcome::Timeout(_) => "timeout".to_owned(),
+        Outcome::OutOfMemory(_) => "out of memory".to_owned(),
+        Outcome::Failed(_) => "failed".to_owned(),
+    }
+}
+
+/// Replaces what is between the markers of the README with the table
+pub fn replace_section(readme: &str, table: &str) -> Result<String, String> {
+    let begin = readme
+        .find(BEGIN_MARKER)
+        .ok_or_else(|| format!("missing {BEGIN_MARKER} marker"))?
+        + BEGIN_MARKER.len();
+
+    let end = readme[begin..]
+        .find(END_MARKER)
+        .ok_or_else(|| format!("missing {END_MARKER} marker after {BEGIN_MARKER}"))?
+        + begin;
+
+    Ok(format!(
+        "{}\n\n{table}\n{}",
+        &readme[..begin],
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use helpers::Day;
+
+    use crate::{
+        isolate::Outcome,
+        readme::{render_table, replace_section, PartResult},
+    };
+
+    const DAY: Day = Day {
+        year: 2023,
+        day: 8,
+        title: "Haunted Wasteland",
+        inputs_dir: "d08/inputs",
+        params: &[],
+        part_1: None,
+        part_2: None,
+    };
+
+    fn solved(verified: Option<bool>) -> PartResult {
+        PartResult {
+            outcome: Outcome::Solved {
+                answer: "6".to_owned(),
+                elapsed: Duration::from_millis(2),
+                allocations: None,
+            },
+            verified,
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let table = render_table(&[(&DAY, [solved(Some(true)), solved(None)])]);
+
+        assert!(table.starts_with("### 2023\n\n| Day | Puzzle |"));
+        assert!(table.contains(
+            "| [d08](d08/src/lib.rs) | [Haunted Wasteland](https://adventofcode.com/2023/day/8) | ⭐⭐ | ✅ 2.00ms | ❔ 2.00ms |"
+        ));
+        assert!(table.contains("✅ answer verified"));
+
+        let timeout = PartResult {
+            outcome: Outcome::Timeout(Duration::from_secs(1)),
+            verified: None,
+        };
+        let table = render_table(&[(&DAY, [solved(Some(false)), timeout])]);
+
+        assert!(table.contains("|  | ❌ 2.00ms | timeout |"));
+
+        const OTHER_YEAR: Day = Day {
+            year: 2024,
+            day: 1,
+            title: "Historian Hysteria",
+            inputs_dir: "2024/d01/inputs",
+            params: &[],
+            part_1: None,
+            part_2: None,
+        };
+        let table = render_table(&[
+            (&DAY, [solved(None), solved(None)]),
+            (&OTHER_YEAR, [solved(None), solved(None)]),
+        ]);
+
+        assert_eq!(table.matches("| Day | Puzzle |").count(), 2);
+        assert!(table.contains("| ⭐⭐ | 2.00ms | 2.00ms |"));
+        assert!(!table.contains('❔'));
+        assert!(!table.contains("answer verified"));
+        assert!(table.contains("\n### 2024\n"));
+        assert!(table.contains("| [d01](2024/d01/src/lib.rs) | [Historian Hysteria](https://adventofcode.com/2024/day/1) |"));
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# AoC\n\n<!-- results:begin -->\nold\n<!-- results:end -->\n\nfooter\n";
+
+        assert_eq!(
+            replace_section(readme, "| new |\n").unwrap(),
+            "# AoC\n\n<!-- results:begin -->\n\n| new |\n\n<!-- results:end -->\n\nfooter\n"
+        );
+
+        assert!(replace_section("# AoC\n", "| new |\n").is_err());
+    }
+}
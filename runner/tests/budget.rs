@@ -0,0 +1,59 @@
+//! Solves parts exceeding their budget in workers of the runner, which kill or starve them.
+
+use std::{env, fs, path::Path, time::Duration};
+
+use runner::{
+    budget::Budget,
+    isolate::{run_part_with, Outcome},
+};
+
+/// Outcome of a part on an input written to a temporary file
+fn run(day: &helpers::Day, part: u8, input: &str, budget: Budget) -> Outcome {
+    let path = env::temp_dir().join(format!("aoc-budget-{}-{}.txt", day.day, std::process::id()));
+    fs::write(&path, input).unwrap();
+
+    let outcome = run_part_with(
+        Path::new(env!("CARGO_BIN_EXE_runner")),
+        day,
+        part,
+        &path,
+        &budget,
+    );
+
+    fs::remove_file(&path).unwrap();
+
+    outcome.unwrap()
+}
+
+#[test]
+fn test_timeout() {
+    let budget = Budget {
+        time: Duration::from_millis(300),
+        memory: None,
+    };
+
+    // the path of day 8 spins on AAA forever
+    assert_eq!(
+        run(&d08::DAY, 1, "L\n\nAAA = (AAA, AAA)\n", budget),
+        Outcome::Timeout(budget.time)
+    );
+}
+
+#[test]
+fn test_out_of_memory() {
+    let budget = Budget {
+        time: Duration::from_secs(10),
+        memory: Some(16 * 1024 * 1024),
+    };
+
+    // day 11 stores the positions of the 4 million galaxies, 16 bytes each
+    assert_eq!(
+        run(
+            &d11::DAY,
+            1,
+            &format!("{}\n", "#".repeat(4_000_000)),
+            budget
+        ),
+        Outcome::OutOfMemory(budget.memory)
+    );
+}
@@ -0,0 +1,275 @@
+//! HTTP service solving the puzzles for the web tools:
+//!
+//! - `POST /solve/{day}/{part}`, optionally `?year=2023`, with the input as the body, answers
+//!   the outcome of the part as JSON, like `runner run --format json`;
+//! - `GET /days` lists the implemented days.
+//!
+//! Each part runs in its own process with the budget of the runner, and bodies are bounded.
+
+use std::{
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use runner::{
+    budget::Budget,
+    find_day,
+    isolate::{run_part, Outcome},
+    latest_year, DAYS,
+};
+use serde_json::{json, Value};
+use tiny_http::{Header, Request};
+
+/// Bodies larger than this are refused, unless configured otherwise
+pub const DEFAULT_MAX_BODY: u64 = 1024 * 1024;
+
+/// Bounds of each request
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Size of the body in bytes
+    pub max_body: u64,
+    pub budget: Budget,
+    /// Year of the days solved without `?year=`, the latest one if `None`
+    pub year: Option<u16>,
+}
+
+/// Status code and JSON body of a response
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// Answers the requests received by the server, until it is shut down
+pub fn serve(server: &tiny_http::Server, limits: &Limits) {
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+
+    for mut request in server.incoming_requests() {
+        let response = handle(&mut request, limits);
+
+        eprintln!("{} {} {}", request.method(), request.url(), response.status);
+
+        let _ = request.respond(
+            tiny_http::Response::from_string(response.body.to_string())
+                .with_status_code(response.status)
+                .with_header(content_type.clone()),
+        );
+    }
+}
+
+fn handle(request: &mut Request, limits: &Limits) -> Response {
+    if request
+        .body_length()
+        .is_some_and(|length| length as u64 > limits.max_body)
+    {
+        return body_too_large(limits);
+    }
+
+    let method = request.method().to_string();
+    let url = request.url().to_owned();
+
+    route(&method, &url, request.as_reader(), limits)
+}
+
+/// Response to a request, reading at most the maximum size of a body
+pub fn route(method: &str, url: &str, body: &mut dyn Read, limits: &Limits) -> Response {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["days"]) => Response {
+            status: 200,
+            body: days(),
+        },
+        ("POST", ["solve", day, part]) => match read_body(body, limits) {
+            Ok(input) => solve(day, part, query, input, limits),
+            Err(response) => response,
+        },
+        (_, ["days"] | ["solve", _, _]) => Response::error(405, format!("{method} not allowed")),
+        _ => Response::error(404, format!("no route for {path}")),
+    }
+}
+
+fn days() -> Value {
+    DAYS.iter()
+        .map(|day| {
+            let parts: Vec<u8> = [1, 2]
+                .into_iter()
+                .filter(|&p| day.part(p).is_some())
+                .collect();
+
+            json!({
+                "year": day.year,
+                "day": day.day,
+                "title": day.title,
+                "parts": parts,
+            })
+        })
+        .collect()
+}
+
+fn read_body(body: &mut dyn Read, limits: &Limits) -> Result<Vec<u8>, Response> {
+    let mut input = vec![];
+
+    // bodies sent without a length are only known to be too large once read
+    body.take(limits.max_body + 1)
+        .read_to_end(&mut input)
+        .map_err(|e| Response::error(400, format!("cannot read the body: {e}")))?;
+
+    if input.len() as u64 > limits.max_body {
+        return Err(body_too_large(limits));
+    }
+
+    Ok(input)
+}
+
+fn body_too_large(limits: &Limits) -> Response {
+    Response::error(
+        413,
+        format!("inputs are limited to {} bytes", limits.max_body),
+    )
+}
+
+fn solve(day: &str, part: &str, query: &str, input: Vec<u8>, limits: &Limits) -> Response {
+    let year = match query.split('&').find_map(|p| p.strip_prefix("year=")) {
+        Some(year) => match year.parse() {
+            Ok(year) => year,
+            Err(_) => return Response::error(400, format!("invalid year {year}")),
+        },
+        None => limits.year.unwrap_or_else(latest_year),
+    };
+
+    let (Ok(day_number), Ok(part)) = (day.parse::<u8>(), part.parse::<u8>()) else {
+        return Response::error(400, format!("invalid day {day} or part {part}"));
+    };
+
+    let Some(day) = find_day(year, day_number).filter(|d| d.part(part).is_some()) else {
+        return Response::error(
+            404,
+            format!("day {day_number} of {year} part {part} is not implemented"),
+        );
+    };
+
+    if std::str::from_utf8(&input).is_err() {
+        return Response::error(400, "the input is not valid UTF-8");
+    }
+
+    let outcome = match with_input_file(&input, |path| run_part(day, part, path, &limits.budget)) {
+        Ok(outcome) => outcome,
+        Err(e) => return Response::error(500, format!("cannot run the solver: {e}")),
+    };
+
+    let status = match outcome {
+        Outcome::Solved { .. } => 200,
+        Outcome::Unimplemented => 404,
+        Outcome::Timeout(_) => 504,
+        Outcome::OutOfMemory(_) | Outcome::Failed(_) => 422,
+    };
+
+    let mut body = outcome.to_json();
+    body["day"] = day.id().into();
+    body["part"] = part.into();
+
+    Response { status, body }
+}
+
+/// Workers read their input from a file, removed once solved or on error.
+///
+/// The file gets a random name and is created only if missing, readable by the server's user
+/// only, so that other local users can neither redirect nor read it.
+fn with_input_file<T>(input: &[u8], f: impl FnOnce(&Path) -> io::Result<T>) -> io::Result<T> {
+    let mut file = tempfile::Builder::new()
+        .prefix("aoc-server-")
+        .suffix(".txt")
+        .tempfile()?;
+
+    file.write_all(input)?;
+    file.flush()?;
+
+    f(file.path())
+}
+
+#[cfg(test)]
+mod tests {
+    use runner::budget::Budget;
+
+    use crate::{route, with_input_file, Limits};
+
+    const LIMITS: Limits = Limits {
+        max_body: 16,
+        budget: Budget {
+            time: std::time::Duration::from_secs(1),
+            memory: None,
+        },
+        year: None,
+    };
+
+    fn status(method: &str, url: &str, body: &str) -> u16 {
+        route(method, url, &mut body.as_bytes(), &LIMITS).status
+    }
+
+    #[test]
+    fn test_days() {
+        let response = route("GET", "/days", &mut "".as_bytes(), &LIMITS);
+
+        assert_eq!(response.status, 200);
+
+        let d09 = response
+            .body
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|day| day["day"] == 9)
+            .unwrap();
+
+        assert_eq!(d09["title"], "Mirage Maintenance");
+        assert_eq!(d09["parts"], serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn test_input_file() {
+        let path = with_input_file(b"0 3 6", |path| {
+            assert_eq!(std::fs::read(path)?, b"0 3 6");
+
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+
+                assert_eq!(std::fs::metadata(path)?.permissions().mode() & 0o777, 0o600);
+            }
+
+            Ok(path.to_owned())
+        })
+        .unwrap();
+        assert!(!path.exists());
+
+        // removed when solving fails too
+        let mut failed = None;
+        let res = with_input_file(b"", |path| {
+            failed = Some(path.to_owned());
+            Err::<(), _>(std::io::Error::other("solver failed"))
+        });
+        assert!(res.is_err());
+        assert!(!failed.unwrap().exists());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(status("GET", "/nothing", ""), 404);
+        assert_eq!(status("GET", "/solve/9/1", ""), 405);
+        assert_eq!(status("POST", "/solve/25/1", ""), 404);
+        assert_eq!(status("POST", "/solve/9/3", ""), 404);
+        assert_eq!(status("POST", "/solve/9/1?year=1999", ""), 404);
+        assert_eq!(status("POST", "/solve/nine/1", ""), 400);
+        assert_eq!(status("POST", "/solve/9/1", "0 3 6 9 12 15 18 21"), 413);
+    }
+}
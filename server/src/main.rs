@@ -0,0 +1,70 @@
+use std::{process::ExitCode, thread};
+
+use clap::Parser;
+use helpers::config::Config;
+use runner::{budget::Budget, isolate::intercept_worker, DAYS};
+use server::{serve, Limits, DEFAULT_MAX_BODY};
+
+#[derive(Parser)]
+#[command(about = "Serves the solvers of the workspace over HTTP")]
+struct Cli {
+    /// Address to listen on, port 0 picking a free one
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    addr: String,
+
+    /// Requests handled at the same time
+    #[arg(long, default_value_t = 4)]
+    workers: usize,
+
+    /// Largest input accepted, in bytes
+    #[arg(long, default_value_t = DEFAULT_MAX_BODY)]
+    max_body: u64,
+
+    /// Wall-clock budget of each part, in seconds, the one of the configuration or 10 if omitted
+    #[arg(short, long)]
+    timeout: Option<f64>,
+
+    /// Memory budget of each part, in MiB, 0 to disable the limit, the one of the configuration
+    /// or 1024 if omitted
+    #[arg(short, long)]
+    memory: Option<u64>,
+}
+
+fn main() -> ExitCode {
+    intercept_worker();
+
+    let cli = Cli::parse();
+
+    let config = match Config::load().and_then(|config| config.check_days(DAYS).map(|_| config)) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("invalid configuration: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let limits = Limits {
+        max_body: cli.max_body,
+        budget: Budget::resolve(cli.timeout, cli.memory, &config),
+        year: config.year,
+    };
+
+    let server = match tiny_http::Server::http(&cli.addr) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("cannot listen on {}: {e}", cli.addr);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // clients started along with the server wait for this line to know the port
+    println!("listening on http://{}", server.server_addr());
+
+    thread::scope(|scope| {
+        for _ in 0..cli.workers.max(1) {
+            scope.spawn(|| serve(&server, &limits));
+        }
+    });
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,103 @@
+//! Starts the server on a free port and queries it like the web tools do.
+
+use std::{
+    io::{BufRead, BufReader},
+    process::{Child, Command, Stdio},
+};
+
+use serde_json::Value;
+
+const REPORT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+/// Server process, killed when dropped
+struct Server {
+    child: Child,
+    url: String,
+}
+
+impl Server {
+    fn start(args: &[&str]) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_server"))
+            .args(["--addr", "127.0.0.1:0"])
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+
+        let url = line
+            .trim()
+            .strip_prefix("listening on ")
+            .unwrap()
+            .to_owned();
+
+        Self { child, url }
+    }
+
+    /// Status and JSON body of a request, whatever the status
+    fn post(&self, path: &str, body: &str) -> (u16, Value) {
+        let response = match ureq::post(&format!("{}{path}", self.url)).send_string(body) {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => panic!("{e}"),
+        };
+
+        let status = response.status();
+
+        (
+            status,
+            serde_json::from_str(&response.into_string().unwrap()).unwrap(),
+        )
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn test_solve() {
+    let server = Server::start(&[]);
+
+    let (status, body) = server.post("/solve/9/1", REPORT);
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "solved");
+    assert_eq!(body["answer"], "114");
+    assert_eq!(body["day"], "2023/d09");
+    assert!(body["elapsed_nanos"].is_u64());
+
+    let (status, body) = server.post("/solve/9/2?year=2023", REPORT);
+    assert_eq!((status, body["answer"].as_str()), (200, Some("2")));
+
+    // the solver of day 11 panics on an empty input
+    let (status, body) = server.post("/solve/11/1", "");
+    assert_eq!((status, body["status"].as_str()), (422, Some("failed")));
+
+    let days = ureq::get(&format!("{}/days", server.url))
+        .call()
+        .unwrap()
+        .into_string()
+        .unwrap();
+    let days: Value = serde_json::from_str(&days).unwrap();
+    assert_eq!(days[0]["title"], "Trebuchet?!");
+}
+
+#[test]
+fn test_limits() {
+    let server = Server::start(&["--max-body", "32", "--timeout", "0.5"]);
+
+    let (status, body) = server.post("/solve/9/1", REPORT);
+    assert_eq!(status, 413);
+    assert_eq!(body["error"], "inputs are limited to 32 bytes");
+
+    // the path of day 8 loops on AAA forever
+    let (status, body) = server.post("/solve/8/1", "L\n\nAAA = (AAA, AAA)\n");
+    assert_eq!((status, body["status"].as_str()), (504, Some("timeout")));
+}